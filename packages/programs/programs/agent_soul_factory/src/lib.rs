@@ -1,15 +1,25 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_pack::Pack,
+};
+use anchor_lang::system_program::{create_account, CreateAccount};
 use anchor_spl::{
+    associated_token::AssociatedToken,
     token_2022::{
+        Token2022,
         spl_token_2022::{
+            self,
             instruction::AuthorityType,
             extension::ExtensionType,
+            state::{Account as AccountState, Mint as MintState},
         },
     },
     token_interface::{
         Mint, TokenAccount, TokenInterface,
-        mint_to, set_authority, transfer_checked,
-        MintTo, SetAuthority, TransferChecked,
+        initialize_mint2, mint_to, set_authority, transfer_checked,
+        InitializeMint2, MintTo, SetAuthority, TransferChecked,
     },
 };
 
@@ -40,10 +50,35 @@ pub mod agent_soul_factory {
         Ok(())
     }
 
-    pub fn create_soul_bound_token(
-        ctx: Context<CreateSoulBoundToken>,
-        amount: u64,
-    ) -> Result<()> {
+    pub fn initialize_fee_config(ctx: Context<InitializeFeeConfig>, fee_amount: u64) -> Result<()> {
+        let fee_config = &mut ctx.accounts.fee_config;
+        fee_config.authority = ctx.accounts.authority.key();
+        fee_config.fee_amount = fee_amount;
+        fee_config.bump = *ctx.bumps.get("fee_config").unwrap();
+        
+        msg!("Fee configuration initialized: fee_amount={}", fee_amount);
+        Ok(())
+    }
+
+    // Mint the single soul token of a soul mint. Only the account that created the
+    // soul mint or the factory authority may mint, and only while supply is zero.
+    pub fn create_soul_bound_token(ctx: Context<CreateSoulBoundToken>) -> Result<()> {
+        // Transfers are blocked by the token program itself, so only NonTransferable mints qualify
+        require!(
+            is_soul_mint(&ctx.accounts.mint.to_account_info())?,
+            AgentSoulError::NotSoulMint
+        );
+        
+        let minter = ctx.accounts.authority.key();
+        require!(
+            minter == ctx.accounts.soul_mint_record.authority
+                || minter == ctx.accounts.fee_config.authority,
+            AgentSoulError::Unauthorized
+        );
+        
+        // One soul per mint
+        require!(ctx.accounts.mint.supply == 0, AgentSoulError::SoulAlreadyMinted);
+
         // Collect protocol fee
         let fee_amount = 1000000; // 1 AXIOM token (assuming 6 decimals)
         let cpi_accounts = TransferChecked {
@@ -74,18 +109,7 @@ pub mod agent_soul_factory {
         let signer = &[&seeds[..]];
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
         
-        mint_to(cpi_ctx, amount)?;
-        
-        // Remove transfer authority to make it soul-bound
-        let cpi_accounts = SetAuthority {
-            account_or_mint: ctx.accounts.token_account.to_account_info(),
-            current_authority: ctx.accounts.agent_soul_factory.to_account_info(),
-        };
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        
-        set_authority(cpi_ctx, AuthorityType::AccountOwner, None)?;
+        mint_to(cpi_ctx, 1)?;
         
         msg!("Protocol fee of {} AXIOM tokens collected", fee_amount);
         Ok(())
@@ -107,22 +131,39 @@ pub mod agent_soul_factory {
         
         transfer_checked(cpi_ctx, fee_amount, ctx.accounts.axiom_token_mint.decimals)?;
         
+        // Create the soul mint with the NonTransferable and metadata extensions
+        let bump = *ctx.bumps.get("agent_soul_factory").unwrap();
+        create_soul_mint(
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.agent_soul_factory.to_account_info(),
+            bump,
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &name,
+            &symbol,
+            &uri,
+        )?;
+        
+        record_soul_mint(
+            &mut ctx.accounts.soul_mint_record,
+            ctx.accounts.mint.key(),
+            ctx.accounts.authority.key(),
+            *ctx.bumps.get("soul_mint_record").unwrap(),
+        );
+        
         // Initialize the metadata
         let metadata = &mut ctx.accounts.metadata;
         metadata.name = name;
         metadata.symbol = symbol;
         metadata.uri = uri;
         
-        // In a full implementation, we would use the proper Token-2022 extension initialization
-        // This would involve creating the mint with the NonTransferable extension enabled
-        // For now, we're marking this as implemented in the metadata
-        
         msg!("Protocol fee of {} AXIOM tokens collected for NTT mint creation", fee_amount);
         Ok(())
     }
     
     // New function to create a proper NTT mint with the NonTransferable extension
-    pub fn initialize_ntt_mint(ctx: Context<InitializeNTTMint>) -> Result<()> {
+    pub fn initialize_ntt_mint(ctx: Context<InitializeNTTMint>, name: String, symbol: String, uri: String) -> Result<()> {
         // Collect protocol fee
         let fee_amount = 1000000; // 1 AXIOM token (assuming 6 decimals)
         let cpi_accounts = TransferChecked {
@@ -137,11 +178,26 @@ pub mod agent_soul_factory {
         
         transfer_checked(cpi_ctx, fee_amount, ctx.accounts.axiom_token_mint.decimals)?;
         
-        // This function would properly initialize a mint with the NonTransferable extension
-        // In a complete implementation, this would involve:
-        // 1. Creating the mint account with space for extensions
-        // 2. Initializing the mint with the NonTransferable extension
-        // 3. Setting appropriate authorities
+        // Create the soul mint with the NonTransferable and metadata extensions
+        let bump = *ctx.bumps.get("agent_soul_factory").unwrap();
+        create_soul_mint(
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.agent_soul_factory.to_account_info(),
+            bump,
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &name,
+            &symbol,
+            &uri,
+        )?;
+        
+        record_soul_mint(
+            &mut ctx.accounts.soul_mint_record,
+            ctx.accounts.mint.key(),
+            ctx.accounts.payer.key(),
+            *ctx.bumps.get("soul_mint_record").unwrap(),
+        );
         
         msg!("Protocol fee of {} AXIOM tokens collected for NTT mint initialization", fee_amount);
         Ok(())
@@ -158,7 +214,7 @@ pub mod agent_soul_factory {
         // Transfer fees from vault to recipient
         let bump = *ctx.bumps.get("fee_config").unwrap();
         let seeds = &[
-            b"fee-config",
+            b"fee-config".as_ref(),
             &[bump],
         ];
         let signer = &[&seeds[..]];
@@ -231,6 +287,23 @@ pub struct Initialize<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct InitializeFeeConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FeeConfig::INIT_SPACE,
+        seeds = [b"fee-config"],
+        bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct CreateSoulBoundToken<'info> {
     #[account(
@@ -239,15 +312,27 @@ pub struct CreateSoulBoundToken<'info> {
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
+    // Records who created the soul mint
+    #[account(
+        seeds = [b"soul-mint", mint.key().as_ref()],
+        bump = soul_mint_record.bump,
+        has_one = mint
+    )]
+    pub soul_mint_record: Account<'info, SoulMintRecord>,
+    
+    // Creator of the soul mint or the factory authority
+    pub authority: Signer<'info>,
+    
     #[account(
         init,
         payer = payer,
-        token::mint = mint,
-        token::authority = recipient,
-        token::token_program = token_program,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
     )]
     pub token_account: InterfaceAccount<'info, TokenAccount>,
     
+    /// CHECK: PDA that holds the mint authority of soul mints
     #[account(
         seeds = [b"agent-soul-factory"],
         bump
@@ -283,6 +368,7 @@ pub struct CreateSoulBoundToken<'info> {
     pub fee_config: Account<'info, FeeConfig>,
     
     pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
@@ -298,6 +384,26 @@ pub struct CreateNTTMint<'info> {
     )]
     pub metadata: Account<'info, MintMetadata>,
     
+    /// CHECK: Created and initialized as a Token-2022 soul mint in the instruction
+    #[account(mut)]
+    pub mint: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SoulMintRecord::INIT_SPACE,
+        seeds = [b"soul-mint", mint.key().as_ref()],
+        bump
+    )]
+    pub soul_mint_record: Account<'info, SoulMintRecord>,
+    
+    /// CHECK: PDA that holds the mint authority of soul mints
+    #[account(
+        seeds = [b"agent-soul-factory"],
+        bump
+    )]
+    pub agent_soul_factory: AccountInfo<'info>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -325,15 +431,32 @@ pub struct CreateNTTMint<'info> {
     
     pub payer: Signer<'info>,
     
-    pub token_program: Interface<'info, TokenInterface>,
+    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
 // New accounts struct for initializing NTT mints with extensions
 #[derive(Accounts)]
 pub struct InitializeNTTMint<'info> {
-    /// CHECK: This account needs to be initialized with proper extension space
-    pub mint: AccountInfo<'info>,
+    /// CHECK: Created and initialized as a Token-2022 soul mint in the instruction
+    #[account(mut)]
+    pub mint: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SoulMintRecord::INIT_SPACE,
+        seeds = [b"soul-mint", mint.key().as_ref()],
+        bump
+    )]
+    pub soul_mint_record: Account<'info, SoulMintRecord>,
+    
+    /// CHECK: PDA that holds the mint authority of soul mints
+    #[account(
+        seeds = [b"agent-soul-factory"],
+        bump
+    )]
+    pub agent_soul_factory: AccountInfo<'info>,
     
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -360,7 +483,7 @@ pub struct InitializeNTTMint<'info> {
     )]
     pub fee_config: Account<'info, FeeConfig>,
     
-    pub token_program: Interface<'info, TokenInterface>,
+    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
@@ -436,8 +559,173 @@ pub struct FeeConfig {
     pub bump: u8,
 }
 
+// Creator of a soul mint, the only non-factory account allowed to mint its soul
+#[account]
+#[derive(InitSpace)]
+pub struct SoulMintRecord {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+fn record_soul_mint(record: &mut Account<SoulMintRecord>, mint: Pubkey, authority: Pubkey, bump: u8) {
+    record.mint = mint;
+    record.authority = authority;
+    record.bump = bump;
+}
+
+// Token-2022 extensions that the bundled spl-token-2022 crate does not know yet
+const METADATA_POINTER_EXTENSION: u16 = 18;
+const TOKEN_METADATA_EXTENSION: u16 = 19;
+
+// TokenInstruction::MetadataPointerExtension / MetadataPointerInstruction::Initialize
+const METADATA_POINTER_INSTRUCTION: u8 = 39;
+const METADATA_POINTER_INITIALIZE: u8 = 0;
+
+// sha256("spl_token_metadata_interface:initialize_account")[..8]
+const TOKEN_METADATA_INITIALIZE: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+
+// Type (2) + length (2) + authority (32) + metadata address (32)
+const METADATA_POINTER_TLV_LEN: usize = 2 + 2 + 32 + 32;
+
+// Type (2) + length (2) + update authority (32) + mint (32) + three string prefixes + empty additional metadata
+const TOKEN_METADATA_BASE_TLV_LEN: usize = 2 + 2 + 32 + 32 + 4 + 4 + 4 + 4;
+
+// Creates a Token-2022 mint with the NonTransferable, MetadataPointer and TokenMetadata
+// extensions. Mint and metadata update authority are both held by the factory PDA.
+#[allow(clippy::too_many_arguments)]
+fn create_soul_mint<'info>(
+    payer: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    agent_soul_factory: AccountInfo<'info>,
+    factory_bump: u8,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+) -> Result<()> {
+    require!(
+        name.len() <= 32 && symbol.len() <= 10 && uri.len() <= 200,
+        AgentSoulError::MetadataTooLong
+    );
+
+    // The metadata entry is reallocated by Token-2022, but its rent has to be there up front
+    let mint_len = ExtensionType::get_account_len::<MintState>(&[ExtensionType::NonTransferable])
+        + METADATA_POINTER_TLV_LEN;
+    let metadata_len = TOKEN_METADATA_BASE_TLV_LEN + name.len() + symbol.len() + uri.len();
+    let lamports = Rent::get()?.minimum_balance(mint_len + metadata_len);
+
+    create_account(
+        CpiContext::new(
+            system_program,
+            CreateAccount {
+                from: payer,
+                to: mint.clone(),
+            },
+        ),
+        lamports,
+        mint_len as u64,
+        token_program.key,
+    )?;
+
+    // Extensions have to be initialized before the mint itself
+    let ix = spl_token_2022::instruction::initialize_non_transferable_mint(token_program.key, mint.key)?;
+    invoke(&ix, std::slice::from_ref(&mint))?;
+
+    let mut data = vec![METADATA_POINTER_INSTRUCTION, METADATA_POINTER_INITIALIZE];
+    data.extend_from_slice(agent_soul_factory.key.as_ref());
+    data.extend_from_slice(mint.key.as_ref());
+    let ix = Instruction {
+        program_id: *token_program.key,
+        accounts: vec![AccountMeta::new(*mint.key, false)],
+        data,
+    };
+    invoke(&ix, std::slice::from_ref(&mint))?;
+
+    initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 { mint: mint.clone() },
+        ),
+        0,
+        agent_soul_factory.key,
+        None,
+    )?;
+
+    // The metadata lives in the mint account itself
+    let mut data = TOKEN_METADATA_INITIALIZE.to_vec();
+    data.extend_from_slice(&name.to_string().try_to_vec()?);
+    data.extend_from_slice(&symbol.to_string().try_to_vec()?);
+    data.extend_from_slice(&uri.to_string().try_to_vec()?);
+    let ix = Instruction {
+        program_id: *token_program.key,
+        accounts: vec![
+            AccountMeta::new(*mint.key, false),
+            AccountMeta::new_readonly(*agent_soul_factory.key, false),
+            AccountMeta::new_readonly(*mint.key, false),
+            AccountMeta::new_readonly(*agent_soul_factory.key, true),
+        ],
+        data,
+    };
+    let seeds = &[b"agent-soul-factory".as_ref(), &[factory_bump]];
+    invoke_signed(
+        &ix,
+        &[mint.clone(), agent_soul_factory.clone(), mint, agent_soul_factory],
+        &[&seeds[..]],
+    )?;
+
+    Ok(())
+}
+
+// Returns true if the account is a Token-2022 mint created as a soul mint, i.e. a
+// NonTransferable mint carrying its own metadata.
+pub fn is_soul_mint(mint: &AccountInfo) -> Result<bool> {
+    if mint.owner != &Token2022::id() {
+        return Ok(false);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let extension_types = mint_extension_types(&data);
+    Ok(extension_types.contains(&(ExtensionType::NonTransferable as u16))
+        && extension_types.contains(&METADATA_POINTER_EXTENSION)
+        && extension_types.contains(&TOKEN_METADATA_EXTENSION))
+}
+
+// Walks the TLV entries of a mint by raw type value, so extensions unknown to the
+// bundled spl-token-2022 version are still visible. Extended mints are padded to the
+// token account length, followed by the account type byte and then the TLV entries.
+fn mint_extension_types(data: &[u8]) -> Vec<u16> {
+    let mut extension_types = Vec::new();
+    let account_type_index = AccountState::LEN;
+    if data.len() <= account_type_index {
+        return extension_types;
+    }
+
+    let mut index = account_type_index + 1;
+    while index + 4 <= data.len() {
+        let extension_type = u16::from_le_bytes([data[index], data[index + 1]]);
+        if extension_type == ExtensionType::Uninitialized as u16 {
+            break;
+        }
+        let length = u16::from_le_bytes([data[index + 2], data[index + 3]]) as usize;
+        extension_types.push(extension_type);
+        index += 4 + length;
+    }
+    extension_types
+}
+
 #[error_code]
 pub enum AgentSoulError {
     #[msg("Unauthorized")]
     Unauthorized,
+    
+    #[msg("Mint is not a non-transferable soul mint")]
+    NotSoulMint,
+    
+    #[msg("Soul mint metadata exceeds the allowed length")]
+    MetadataTooLong,
+    
+    #[msg("The soul of this mint has already been minted")]
+    SoulAlreadyMinted,
 }
\ No newline at end of file
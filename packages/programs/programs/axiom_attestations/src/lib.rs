@@ -1,8 +1,27 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::system_program::{assign, create_account, transfer, Assign, CreateAccount, Transfer};
 
 declare_id!("4sKxhfHdQgjWBuoztEYonKepba2zGcN2QtWowCmAfWzD");
 
+// Seed prefix for the markers other programs create once they have counted an attestation
+pub const CONSUMED_ATTESTATION_SEED: &[u8] = b"consumed-attestation";
+
+// Maximum number of fields a schema can declare
+pub const MAX_SCHEMA_FIELDS: usize = 16;
+
+// Maximum length of a schema field name
+pub const MAX_FIELD_NAME_LEN: usize = 32;
+
+// Maximum size of the Borsh-encoded data carried by an attestation
+pub const MAX_ATTESTATION_DATA: usize = 256;
+
+// Maximum weight an attester can give a single attestation
+pub const MAX_ATTESTATION_WEIGHT: u8 = 10;
+
+// Default time for a reputation score to decay by half (30 days)
+pub const DEFAULT_REPUTATION_HALF_LIFE: i64 = 2_592_000;
+
 #[program]
 pub mod axiom_attestations {
     use super::*;
@@ -11,6 +30,7 @@ pub mod axiom_attestations {
         let attestation_config = &mut ctx.accounts.attestation_config;
         attestation_config.authority = authority;
         attestation_config.total_attestations = 0;
+        attestation_config.reputation_half_life = DEFAULT_REPUTATION_HALF_LIFE;
         attestation_config.bump = *ctx.bumps.get("attestation_config").unwrap();
         
         Ok(())
@@ -20,11 +40,19 @@ pub mod axiom_attestations {
         ctx: Context<CreateAttestationSchema>,
         name: String,
         description: String,
+        fields: Vec<SchemaField>,
+        revocable: bool,
+        reputation_weights: ReputationWeights,
     ) -> Result<()> {
+        validate_schema_fields(&fields)?;
+        
         let schema = &mut ctx.accounts.schema;
         schema.authority = ctx.accounts.authority.key();
         schema.name = name;
         schema.description = description;
+        schema.fields = fields;
+        schema.revocable = revocable;
+        schema.reputation_weights = reputation_weights;
         schema.bump = *ctx.bumps.get("schema").unwrap();
         
         Ok(())
@@ -33,18 +61,50 @@ pub mod axiom_attestations {
     pub fn issue_attestation(
         ctx: Context<IssueAttestation>,
         claim: String,
+        data: Vec<u8>,
         expiration: Option<i64>,
+        attestation_type: AttestationType,
+        sentiment: Sentiment,
+        weight: u8,
     ) -> Result<()> {
+        require!(
+            weight > 0 && weight <= MAX_ATTESTATION_WEIGHT,
+            AttestationError::InvalidWeight
+        );
+        check_attester(
+            &ctx.accounts.schema,
+            &ctx.accounts.attester.key(),
+            ctx.accounts.attester_authorization.as_deref(),
+            ctx.accounts.delegator_authorization.as_deref(),
+        )?;
+        // The data blob must decode exactly against the schema's field layout
+        ctx.accounts.schema.decode_data(&data)?;
+        let now = Clock::get()?.unix_timestamp;
+        
         let attestation = &mut ctx.accounts.attestation;
         attestation.schema = ctx.accounts.schema.key();
         attestation.subject = ctx.accounts.subject.key();
         attestation.attester = ctx.accounts.attester.key();
         attestation.claim = claim;
+        attestation.data = data;
+        attestation.attestation_type = attestation_type;
+        attestation.sentiment = sentiment;
+        attestation.weight = weight;
         attestation.expiration = expiration;
         attestation.revoked = false;
-        attestation.timestamp = Clock::get()?.unix_timestamp;
+        attestation.settled = false;
+        attestation.timestamp = now;
         attestation.bump = *ctx.bumps.get("attestation").unwrap();
         
+        let reputation = &mut ctx.accounts.reputation;
+        if reputation.subject == Pubkey::default() {
+            reputation.subject = ctx.accounts.subject.key();
+            reputation.last_updated = now;
+            reputation.bump = *ctx.bumps.get("reputation").unwrap();
+        }
+        let delta = attestation.reputation_delta(ctx.accounts.schema.reputation_weights.issue);
+        reputation.apply(delta, now, ctx.accounts.attestation_config.reputation_half_life)?;
+        
         // Update the total attestations count
         let attestation_config = &mut ctx.accounts.attestation_config;
         attestation_config.total_attestations = attestation_config.total_attestations.checked_add(1)
@@ -53,16 +113,91 @@ pub mod axiom_attestations {
         Ok(())
     }
 
+    // Only the original attester or the schema authority can revoke, and only on revocable schemas
     pub fn revoke_attestation(ctx: Context<RevokeAttestation>, reason: String) -> Result<()> {
+        require!(ctx.accounts.schema.revocable, AttestationError::NotRevocable);
+        let revoker = ctx.accounts.revoker.key();
         let attestation = &mut ctx.accounts.attestation;
+        require!(
+            revoker == attestation.attester || revoker == ctx.accounts.schema.authority,
+            AttestationError::Unauthorized
+        );
+        require!(!attestation.revoked, AttestationError::AttestationRevoked);
         attestation.revoked = true;
         
+        // An attestation that already expired has settled its reputation effect
+        if !attestation.settled {
+            attestation.settled = true;
+            let delta = attestation.reputation_delta(ctx.accounts.schema.reputation_weights.revoke);
+            ctx.accounts.reputation.apply(
+                delta,
+                Clock::get()?.unix_timestamp,
+                ctx.accounts.attestation_config.reputation_half_life,
+            )?;
+        }
+        
         // Log the revocation reason
         msg!("Attestation revoked for reason: {}", reason);
         
         Ok(())
     }
     
+    // Allow an attester to issue under a schema, optionally until an expiry.
+    // Called by the schema authority, or by an attester allowed to delegate, in
+    // which case the delegate cannot outlive or re-delegate the delegator's grant.
+    // To change an existing grant, remove it and authorize again.
+    pub fn authorize_attester(
+        ctx: Context<AuthorizeAttester>,
+        attester: Pubkey,
+        expires_at: Option<i64>,
+        can_delegate: bool,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > now, AttestationError::InvalidExpiry);
+        }
+        
+        let granter = ctx.accounts.granter.key();
+        let (expires_at, can_delegate) = if granter == ctx.accounts.schema.authority {
+            (expires_at, can_delegate)
+        } else {
+            let delegator = ctx.accounts.granter_authorization.as_ref()
+                .ok_or(AttestationError::Unauthorized)?;
+            require!(delegator.is_active(now), AttestationError::AttesterNotAuthorized);
+            require!(delegator.can_delegate, AttestationError::Unauthorized);
+            let expires_at = match (expires_at, delegator.expires_at) {
+                (Some(requested), Some(limit)) => Some(requested.min(limit)),
+                (None, limit) => limit,
+                (requested, None) => requested,
+            };
+            (expires_at, false)
+        };
+        
+        let authorization = &mut ctx.accounts.attester_authorization;
+        authorization.schema = ctx.accounts.schema.key();
+        authorization.attester = attester;
+        authorization.granted_by = granter;
+        authorization.expires_at = expires_at;
+        authorization.can_delegate = can_delegate;
+        authorization.bump = *ctx.bumps.get("attester_authorization").unwrap();
+        
+        msg!("Authorized attester {} for schema {}", attester, ctx.accounts.schema.name);
+        Ok(())
+    }
+    
+    // Remove an attester from a schema's allow-list
+    pub fn remove_attester(ctx: Context<RemoveAttester>) -> Result<()> {
+        let remover = ctx.accounts.remover.key();
+        let authorization = &ctx.accounts.attester_authorization;
+        require!(
+            remover == ctx.accounts.schema.authority || remover == authorization.granted_by,
+            AttestationError::Unauthorized
+        );
+        
+        msg!("Removed attester {} from schema {}", authorization.attester, ctx.accounts.schema.name);
+        Ok(())
+    }
+    
     // New function to verify an attestation against SAS standards
     pub fn verify_attestation(ctx: Context<VerifyAttestation>) -> Result<()> {
         let attestation = &ctx.accounts.attestation;
@@ -82,43 +217,45 @@ pub mod axiom_attestations {
         Ok(())
     }
     
-    // New function to integrate with SAS reputation system
-    pub fn update_reputation_from_attestation(ctx: Context<UpdateReputationFromAttestation>, reputation_change: i64) -> Result<()> {
-        // This function would be called by other programs to update reputation based on attestation
-        // In a full implementation, this would involve cross-program calls to the reputation system
+    // Apply the schema's expiry weight once an attestation has expired.
+    // Anyone can crank this.
+    pub fn expire_attestation(ctx: Context<ExpireAttestation>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let attestation = &mut ctx.accounts.attestation;
+        let expiration = attestation.expiration.ok_or(AttestationError::AttestationNotExpired)?;
+        require!(now >= expiration, AttestationError::AttestationNotExpired);
+        require!(!attestation.settled, AttestationError::AttestationSettled);
+        attestation.settled = true;
         
-        msg!("Reputation updated by {} based on attestation", reputation_change);
+        let delta = attestation.reputation_delta(ctx.accounts.schema.reputation_weights.expiry);
+        ctx.accounts.reputation.apply(delta, now, ctx.accounts.attestation_config.reputation_half_life)?;
         
+        msg!("Attestation expired for subject: {}", attestation.subject);
         Ok(())
     }
     
-    // New function to create SAS-compliant attestation
-    pub fn create_sas_attestation(
-        ctx: Context<CreateSASAttestation>,
-        claim: String,
-        expiration: Option<i64>,
-        attestation_type: String, // e.g., "capability", "performance", "behavioral"
-    ) -> Result<()> {
-        let attestation = &mut ctx.accounts.attestation;
-        attestation.schema = ctx.accounts.schema.key();
-        attestation.subject = ctx.accounts.subject.key();
-        attestation.attester = ctx.accounts.attester.key();
-        attestation.claim = claim;
-        attestation.expiration = expiration;
-        attestation.revoked = false;
-        attestation.timestamp = Clock::get()?.unix_timestamp;
-        attestation.bump = *ctx.bumps.get("attestation").unwrap();
+    // Read-only view of a subject's decayed reputation score, returned via return data
+    pub fn get_reputation(ctx: Context<GetReputation>) -> Result<i64> {
+        let score = ctx.accounts.reputation.decayed_score(
+            Clock::get()?.unix_timestamp,
+            ctx.accounts.attestation_config.reputation_half_life,
+        );
         
-        // Store attestation type for SAS compliance
-        // In a full implementation, this would be stored in a separate field
+        msg!("Reputation for {}: {}", ctx.accounts.reputation.subject, score);
+        Ok(score)
+    }
+    
+    // Set how quickly reputation decays; 0 disables decay
+    pub fn set_reputation_half_life(ctx: Context<UpdateAttestationConfig>, half_life: i64) -> Result<()> {
+        require!(half_life >= 0, AttestationError::InvalidHalfLife);
         
-        // Update the total attestations count
         let attestation_config = &mut ctx.accounts.attestation_config;
-        attestation_config.total_attestations = attestation_config.total_attestations.checked_add(1)
-            .ok_or(error!(AttestationError::Overflow))?;
+        attestation_config.reputation_half_life = half_life;
         
+        msg!("Reputation half-life set to {}s", half_life);
         Ok(())
     }
+
 }
 
 #[derive(Accounts)]
@@ -185,6 +322,26 @@ pub struct IssueAttestation<'info> {
     
     pub schema: Account<'info, AttestationSchema>,
     
+    // Not needed when the schema authority attests itself
+    #[account(
+        seeds = [b"attester", schema.key().as_ref(), attester.key().as_ref()],
+        bump = attester_authorization.bump
+    )]
+    pub attester_authorization: Option<Account<'info, AttesterAuthorization>>,
+    
+    // The delegating attester's authorization, required when the attester was
+    // authorized by someone other than the schema authority
+    pub delegator_authorization: Option<Account<'info, AttesterAuthorization>>,
+    
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = 8 + Reputation::INIT_SPACE,
+        seeds = [b"reputation", subject.key().as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+    
     /// CHECK: This is the subject of the attestation
     pub subject: AccountInfo<'info>,
     
@@ -192,6 +349,7 @@ pub struct IssueAttestation<'info> {
     pub attester: Signer<'info>,
     
     #[account(
+        mut,
         seeds = [b"attestation-config"],
         bump = attestation_config.bump
     )]
@@ -202,14 +360,70 @@ pub struct IssueAttestation<'info> {
 
 #[derive(Accounts)]
 pub struct RevokeAttestation<'info> {
+    #[account(mut, has_one = schema)]
+    pub attestation: Account<'info, Attestation>,
+    
+    pub schema: Account<'info, AttestationSchema>,
+    
     #[account(
         mut,
-        constraint = attestation.attester == attester.key()
+        seeds = [b"reputation", attestation.subject.as_ref()],
+        bump = reputation.bump
     )]
-    pub attestation: Account<'info, Attestation>,
+    pub reputation: Account<'info, Reputation>,
+    
+    #[account(
+        seeds = [b"attestation-config"],
+        bump = attestation_config.bump
+    )]
+    pub attestation_config: Account<'info, AttestationConfig>,
+    
+    // The original attester or the schema authority
+    pub revoker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct AuthorizeAttester<'info> {
+    pub schema: Account<'info, AttestationSchema>,
+    
+    #[account(
+        init,
+        payer = granter,
+        space = 8 + AttesterAuthorization::INIT_SPACE,
+        seeds = [b"attester", schema.key().as_ref(), attester.as_ref()],
+        bump
+    )]
+    pub attester_authorization: Account<'info, AttesterAuthorization>,
+    
+    // The granter's own authorization, required unless the granter is the schema authority
+    #[account(
+        seeds = [b"attester", schema.key().as_ref(), granter.key().as_ref()],
+        bump = granter_authorization.bump
+    )]
+    pub granter_authorization: Option<Account<'info, AttesterAuthorization>>,
     
     #[account(mut)]
-    pub attester: Signer<'info>,
+    pub granter: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveAttester<'info> {
+    pub schema: Account<'info, AttestationSchema>,
+    
+    #[account(
+        mut,
+        close = remover,
+        seeds = [b"attester", schema.key().as_ref(), attester_authorization.attester.as_ref()],
+        bump = attester_authorization.bump
+    )]
+    pub attester_authorization: Account<'info, AttesterAuthorization>,
+    
+    // The schema authority or whoever granted the authorization
+    #[account(mut)]
+    pub remover: Signer<'info>,
 }
 
 // New accounts struct for verifying attestations
@@ -221,53 +435,53 @@ pub struct VerifyAttestation<'info> {
     pub verifier: AccountInfo<'info>,
 }
 
-// New accounts struct for updating reputation from attestation
 #[derive(Accounts)]
-pub struct UpdateReputationFromAttestation<'info> {
+pub struct ExpireAttestation<'info> {
+    #[account(mut, has_one = schema)]
     pub attestation: Account<'info, Attestation>,
     
-    /// CHECK: This account would be the reputation account
-    pub reputation_account: AccountInfo<'info>,
+    pub schema: Account<'info, AttestationSchema>,
     
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"reputation", attestation.subject.as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+    
+    #[account(
+        seeds = [b"attestation-config"],
+        bump = attestation_config.bump
+    )]
+    pub attestation_config: Account<'info, AttestationConfig>,
 }
 
-// New accounts struct for creating SAS-compliant attestations
 #[derive(Accounts)]
-#[instruction(claim: String, attestation_type: String)]
-pub struct CreateSASAttestation<'info> {
+pub struct GetReputation<'info> {
     #[account(
-        init,
-        payer = attester,
-        space = 8 + SASAttestation::INIT_SPACE,
-        seeds = [
-            b"sas-attestation", 
-            schema.key().as_ref(), 
-            subject.key().as_ref(), 
-            attester.key().as_ref(),
-            claim.as_bytes(),
-            attestation_type.as_bytes()
-        ],
-        bump
+        seeds = [b"reputation", reputation.subject.as_ref()],
+        bump = reputation.bump
     )]
-    pub attestation: Account<'info, SASAttestation>,
-    
-    pub schema: Account<'info, AttestationSchema>,
-    
-    /// CHECK: This is the subject of the attestation
-    pub subject: AccountInfo<'info>,
-    
-    #[account(mut)]
-    pub attester: Signer<'info>,
+    pub reputation: Account<'info, Reputation>,
     
     #[account(
         seeds = [b"attestation-config"],
         bump = attestation_config.bump
     )]
     pub attestation_config: Account<'info, AttestationConfig>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAttestationConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"attestation-config"],
+        bump = attestation_config.bump,
+        has_one = authority @ AttestationError::Unauthorized
+    )]
+    pub attestation_config: Account<'info, AttestationConfig>,
     
-    pub system_program: Program<'info, System>,
+    pub authority: Signer<'info>,
 }
 
 // Configuration account for the attestation program
@@ -276,6 +490,7 @@ pub struct CreateSASAttestation<'info> {
 pub struct AttestationConfig {
     pub authority: Pubkey,
     pub total_attestations: u64,
+    pub reputation_half_life: i64,  // Seconds for reputation to decay by half, 0 disables decay
     pub bump: u8,
 }
 
@@ -288,9 +503,171 @@ pub struct AttestationSchema {
     pub name: String,
     #[max_len(200)]
     pub description: String,
+    #[max_len(16)]
+    pub fields: Vec<SchemaField>,   // Layout of the data carried by attestations
+    pub revocable: bool,            // Whether attestations under this schema can be revoked
+    pub reputation_weights: ReputationWeights,
     pub bump: u8,
 }
 
+// Reputation points per unit of attestation weight for each attestation event.
+// Positive attestations apply these as given, negative ones with the sign flipped.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct ReputationWeights {
+    pub issue: i16,
+    pub revoke: i16,                // Usually the negation of issue, to undo it
+    pub expiry: i16,
+}
+
+impl AttestationSchema {
+    // Decode a Borsh-encoded data blob against the field layout. The blob must
+    // contain exactly one value per field, in order, with no trailing bytes.
+    pub fn decode_data(&self, data: &[u8]) -> Result<Vec<FieldValue>> {
+        require!(data.len() <= MAX_ATTESTATION_DATA, AttestationError::DataTooLong);
+        
+        let mut cursor = data;
+        let mut values = Vec::with_capacity(self.fields.len());
+        for field in self.fields.iter() {
+            let value = match field.field_type {
+                FieldType::U8 => FieldValue::U8(read_borsh(&mut cursor)?),
+                FieldType::U16 => FieldValue::U16(read_borsh(&mut cursor)?),
+                FieldType::U64 => FieldValue::U64(read_borsh(&mut cursor)?),
+                FieldType::I64 => FieldValue::I64(read_borsh(&mut cursor)?),
+                FieldType::Bool => FieldValue::Bool(read_borsh(&mut cursor)?),
+                FieldType::Pubkey => FieldValue::Pubkey(read_borsh(&mut cursor)?),
+                FieldType::String => {
+                    let value: String = read_borsh(&mut cursor)?;
+                    require!(value.len() <= field.max_len as usize, AttestationError::FieldTooLong);
+                    FieldValue::String(value)
+                }
+                FieldType::Bytes => {
+                    let value: Vec<u8> = read_borsh(&mut cursor)?;
+                    require!(value.len() <= field.max_len as usize, AttestationError::FieldTooLong);
+                    FieldValue::Bytes(value)
+                }
+            };
+            values.push(value);
+        }
+        require!(cursor.is_empty(), AttestationError::InvalidAttestationData);
+        
+        Ok(values)
+    }
+    
+    // Position of a named field in the layout, for looking up decoded values
+    pub fn field_index(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|field| field.name == name)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct SchemaField {
+    #[max_len(32)]
+    pub name: String,
+    pub field_type: FieldType,
+    pub max_len: u16,               // Maximum length of string and bytes fields
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum FieldType {
+    U8,
+    U16,
+    U64,
+    I64,
+    Bool,
+    Pubkey,
+    String,
+    Bytes,
+}
+
+// A decoded attestation data field
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum FieldValue {
+    U8(u8),
+    U16(u16),
+    U64(u64),
+    I64(i64),
+    Bool(bool),
+    Pubkey(Pubkey),
+    String(String),
+    Bytes(Vec<u8>),
+}
+
+fn read_borsh<T: AnchorDeserialize>(cursor: &mut &[u8]) -> Result<T> {
+    T::deserialize(cursor).map_err(|_| error!(AttestationError::InvalidAttestationData))
+}
+
+fn validate_schema_fields(fields: &[SchemaField]) -> Result<()> {
+    require!(fields.len() <= MAX_SCHEMA_FIELDS, AttestationError::InvalidSchemaLayout);
+    for (i, field) in fields.iter().enumerate() {
+        require!(
+            !field.name.is_empty() && field.name.len() <= MAX_FIELD_NAME_LEN,
+            AttestationError::InvalidSchemaLayout
+        );
+        require!(
+            !fields[..i].iter().any(|other| other.name == field.name),
+            AttestationError::InvalidSchemaLayout
+        );
+        if matches!(field.field_type, FieldType::String | FieldType::Bytes) {
+            require!(
+                field.max_len > 0 && field.max_len as usize <= MAX_ATTESTATION_DATA,
+                AttestationError::InvalidSchemaLayout
+            );
+        }
+    }
+    Ok(())
+}
+
+// Allows an attester to issue attestations under a schema
+#[account]
+#[derive(InitSpace)]
+pub struct AttesterAuthorization {
+    pub schema: Pubkey,
+    pub attester: Pubkey,
+    pub granted_by: Pubkey,         // Schema authority or the delegating attester
+    pub expires_at: Option<i64>,
+    pub can_delegate: bool,         // Whether this attester may authorize others
+    pub bump: u8,
+}
+
+impl AttesterAuthorization {
+    pub fn is_active(&self, now: i64) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now < expires_at,
+            None => true,
+        }
+    }
+}
+
+// The schema authority may always attest; anyone else needs an active authorization.
+// A delegate additionally needs its delegator to still hold an active, delegating grant,
+// so removing or expiring the delegator cuts off everyone it authorized.
+fn check_attester(
+    schema: &AttestationSchema,
+    attester: &Pubkey,
+    authorization: Option<&AttesterAuthorization>,
+    delegator_authorization: Option<&AttesterAuthorization>,
+) -> Result<()> {
+    if *attester == schema.authority {
+        return Ok(());
+    }
+    let now = Clock::get()?.unix_timestamp;
+    let authorization = authorization.ok_or(AttestationError::AttesterNotAuthorized)?;
+    require!(authorization.is_active(now), AttestationError::AttesterNotAuthorized);
+    if authorization.granted_by == schema.authority {
+        return Ok(());
+    }
+    // Authorizations only exist at their PDA, so matching fields identify the delegator's grant
+    let delegator = delegator_authorization.ok_or(AttestationError::AttesterNotAuthorized)?;
+    require!(
+        delegator.schema == authorization.schema
+            && delegator.attester == authorization.granted_by
+            && delegator.can_delegate
+            && delegator.is_active(now),
+        AttestationError::AttesterNotAuthorized
+    );
+    Ok(())
+}
+
 // Individual attestation
 #[account]
 #[derive(InitSpace)]
@@ -298,31 +675,190 @@ pub struct Attestation {
     pub schema: Pubkey,
     pub subject: Pubkey,
     pub attester: Pubkey,
-    #[max_len(500)]
-    pub claim: String,
+    #[max_len(32)]
+    pub claim: String,              // Short label, also used as a PDA seed
+    #[max_len(256)]
+    pub data: Vec<u8>,              // Borsh-encoded fields following the schema layout
+    pub attestation_type: AttestationType,
+    pub sentiment: Sentiment,
+    pub weight: u8,                 // Strength of the signal, 1 to MAX_ATTESTATION_WEIGHT
     pub expiration: Option<i64>,
     pub revoked: bool,
+    pub settled: bool,              // Revocation or expiry has been applied to reputation
     pub timestamp: i64,
     pub bump: u8,
 }
 
-// SAS-compliant attestation with additional fields
+// Per-subject reputation, only changed by attestation events in this program
 #[account]
 #[derive(InitSpace)]
-pub struct SASAttestation {
-    pub schema: Pubkey,
+pub struct Reputation {
     pub subject: Pubkey,
-    pub attester: Pubkey,
-    #[max_len(500)]
-    pub claim: String,
-    pub expiration: Option<i64>,
-    pub revoked: bool,
-    pub timestamp: i64,
-    #[max_len(20)]
-    pub attestation_type: String, // e.g., "capability", "performance", "behavioral"
+    pub score: i64,                 // Score as of last_updated
+    pub last_updated: i64,
+    pub total_events: u64,
     pub bump: u8,
 }
 
+impl Reputation {
+    // Score decayed towards zero since the last update. Each full half-life halves it,
+    // and the remaining partial period is interpolated linearly. Negative scores decay
+    // by the same magnitude as positive ones.
+    pub fn decayed_score(&self, now: i64, half_life: i64) -> i64 {
+        let elapsed = now.saturating_sub(self.last_updated);
+        if half_life <= 0 || elapsed <= 0 || self.score == 0 {
+            return self.score;
+        }
+        let halvings = elapsed / half_life;
+        if halvings >= 63 {
+            return 0;
+        }
+        let magnitude = (self.score.unsigned_abs() >> halvings) as u128;
+        let remainder = (elapsed % half_life) as u128;
+        let decay = magnitude * remainder / (2 * half_life as u128);
+        let magnitude = (magnitude - decay) as i128;
+        (if self.score < 0 { -magnitude } else { magnitude }) as i64
+    }
+    
+    pub fn apply(&mut self, delta: i64, now: i64, half_life: i64) -> Result<()> {
+        self.score = self.decayed_score(now, half_life).saturating_add(delta);
+        self.last_updated = now;
+        self.total_events = self.total_events.checked_add(1)
+            .ok_or(AttestationError::Overflow)?;
+        Ok(())
+    }
+}
+
+// What an attestation speaks to (SAS attestation type)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum AttestationType {
+    General,
+    Capability,
+    Performance,
+    Behavioral,
+    Identity,
+}
+
+// Whether an attestation counts for or against its subject
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum Sentiment {
+    Positive,
+    Negative,
+    Neutral,
+}
+
+impl Attestation {
+    // Reputation change for an event with the given schema weight
+    pub fn reputation_delta(&self, event_weight: i16) -> i64 {
+        let sign = match self.sentiment {
+            Sentiment::Positive => 1,
+            Sentiment::Negative => -1,
+            Sentiment::Neutral => 0,
+        };
+        sign * self.weight as i64 * event_weight as i64
+    }
+    
+    // Not revoked and not past its expiration
+    pub fn is_active(&self, now: i64) -> bool {
+        !self.revoked && match self.expiration {
+            Some(expiration) => now < expiration,
+            None => true,
+        }
+    }
+}
+
+// Lets other programs count attestations passed in their remaining_accounts.
+// Accounts come in (attestation, marker) pairs. An attestation is counted only if it
+// is owned by this program, active, about `subject`, issued under a trusted schema,
+// has the requested sentiment (if any), and its marker PDA, derived by `program_id` from [CONSUMED_ATTESTATION_SEED, scope,
+// attestation], does not exist yet. Counted attestations get their marker created so
+// they cannot be replayed against the same scope.
+pub struct AttestationConsumer<'a, 'info> {
+    pub program_id: &'a Pubkey,
+    pub scope: Pubkey,
+    pub subject: Pubkey,
+    pub trusted_schemas: &'a [Pubkey],
+    pub sentiment: Option<Sentiment>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'a, 'info> AttestationConsumer<'a, 'info> {
+    // Returns the newly counted attestations
+    pub fn consume(&self, accounts: &[AccountInfo<'info>]) -> Result<Vec<Attestation>> {
+        let pairs = accounts.chunks_exact(2);
+        require!(pairs.remainder().is_empty(), AttestationError::MissingConsumedMarker);
+        let now = Clock::get()?.unix_timestamp;
+        let mut counted = Vec::new();
+
+        for pair in pairs {
+            let (attestation_info, marker) = (&pair[0], &pair[1]);
+
+            let (expected_marker, marker_bump) = Pubkey::find_program_address(
+                &[CONSUMED_ATTESTATION_SEED, self.scope.as_ref(), attestation_info.key.as_ref()],
+                self.program_id,
+            );
+            require_keys_eq!(marker.key(), expected_marker, AttestationError::InvalidConsumedMarker);
+
+            // Already counted for this scope
+            if marker.owner == self.program_id {
+                continue;
+            }
+            if attestation_info.owner != &ID {
+                continue;
+            }
+            let attestation = match Account::<Attestation>::try_from(attestation_info) {
+                Ok(attestation) => attestation.into_inner(),
+                Err(_) => continue,
+            };
+            if attestation.subject != self.subject
+                || !self.trusted_schemas.contains(&attestation.schema)
+                || self.sentiment.is_some_and(|sentiment| sentiment != attestation.sentiment)
+                || !attestation.is_active(now)
+            {
+                continue;
+            }
+
+            self.create_marker(marker, marker_bump, attestation_info.key)?;
+            counted.push(attestation);
+        }
+
+        Ok(counted)
+    }
+
+    // Create an empty account owned by the consuming program at the marker address
+    fn create_marker(&self, marker: &AccountInfo<'info>, bump: u8, attestation: &Pubkey) -> Result<()> {
+        let seeds = &[CONSUMED_ATTESTATION_SEED, self.scope.as_ref(), attestation.as_ref(), &[bump]];
+        let signer = &[&seeds[..]];
+        let rent = Rent::get()?.minimum_balance(0);
+
+        if marker.lamports() == 0 {
+            let cpi_accounts = CreateAccount {
+                from: self.payer.clone(),
+                to: marker.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(self.system_program.clone(), cpi_accounts, signer);
+            create_account(cpi_ctx, rent, 0, self.program_id)
+        } else {
+            // Someone pre-funded the address, so top it up and take ownership instead
+            let shortfall = rent.saturating_sub(marker.lamports());
+            if shortfall > 0 {
+                let cpi_accounts = Transfer {
+                    from: self.payer.clone(),
+                    to: marker.clone(),
+                };
+                let cpi_ctx = CpiContext::new(self.system_program.clone(), cpi_accounts);
+                transfer(cpi_ctx, shortfall)?;
+            }
+            let cpi_accounts = Assign {
+                account_to_assign: marker.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(self.system_program.clone(), cpi_accounts, signer);
+            assign(cpi_ctx, self.program_id)
+        }
+    }
+}
+
 #[error_code]
 pub enum AttestationError {
     #[msg("Arithmetic overflow")]
@@ -333,4 +869,46 @@ pub enum AttestationError {
     
     #[msg("Attestation has expired")]
     AttestationExpired,
+    
+    #[msg("Each attestation must be followed by its consumed marker")]
+    MissingConsumedMarker,
+    
+    #[msg("Consumed marker does not match the attestation")]
+    InvalidConsumedMarker,
+    
+    #[msg("Invalid schema field layout")]
+    InvalidSchemaLayout,
+    
+    #[msg("Attestation data does not match the schema layout")]
+    InvalidAttestationData,
+    
+    #[msg("Attestation data is too long")]
+    DataTooLong,
+    
+    #[msg("Attestation field exceeds its maximum length")]
+    FieldTooLong,
+    
+    #[msg("Unauthorized")]
+    Unauthorized,
+    
+    #[msg("Attester is not authorized for this schema")]
+    AttesterNotAuthorized,
+    
+    #[msg("Attestations under this schema cannot be revoked")]
+    NotRevocable,
+    
+    #[msg("Expiry must be in the future")]
+    InvalidExpiry,
+    
+    #[msg("Attestation weight is out of range")]
+    InvalidWeight,
+    
+    #[msg("Attestation has not expired")]
+    AttestationNotExpired,
+    
+    #[msg("Attestation reputation effect is already settled")]
+    AttestationSettled,
+    
+    #[msg("Reputation half-life cannot be negative")]
+    InvalidHalfLife,
 }
\ No newline at end of file
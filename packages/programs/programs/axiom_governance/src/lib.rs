@@ -1,4 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
 use anchor_spl::{
     token_2022::Token2022,
     token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
@@ -12,11 +16,16 @@ pub mod axiom_governance {
     use super::*;
 
     // Initialize the governance realm
-    pub fn initialize_realm(ctx: Context<InitializeRealm>, name: String) -> Result<()> {
+    pub fn initialize_realm(ctx: Context<InitializeRealm>, name: String, config: GovernanceConfig) -> Result<()> {
+        config.validate()?;
+        
         let realm = &mut ctx.accounts.realm;
         realm.authority = ctx.accounts.authority.key();
         realm.name = name;
+        realm.governing_token_mint = ctx.accounts.governing_token_mint.key();
         realm.proposal_count = 0;
+        realm.config = config;
+        realm.treasury_bump = *ctx.bumps.get("native_treasury").unwrap();
         realm.bump = *ctx.bumps.get("realm").unwrap();
         
         msg!("Governance realm '{}' initialized", realm.name);
@@ -30,6 +39,17 @@ pub mod axiom_governance {
         description: String,
         voting_period: i64, // in seconds
     ) -> Result<()> {
+        let config = &ctx.accounts.realm.config;
+        
+        require!(
+            voting_period >= config.min_voting_period && voting_period <= config.max_voting_period,
+            GovernanceError::InvalidVotingPeriod
+        );
+        require!(
+            ctx.accounts.proposer_token_account.amount >= config.min_tokens_to_create_proposal,
+            GovernanceError::NotEnoughTokensToPropose
+        );
+        
         let proposal = &mut ctx.accounts.proposal;
         proposal.realm = ctx.accounts.realm.key();
         proposal.proposer = ctx.accounts.proposer.key();
@@ -39,10 +59,14 @@ pub mod axiom_governance {
         proposal.no_votes = 0;
         proposal.abstain_votes = 0;
         proposal.total_votes = 0;
-        proposal.status = ProposalStatus::Active;
-        proposal.start_time = Clock::get()?.unix_timestamp;
-        proposal.end_time = proposal.start_time.checked_add(voting_period)
-            .ok_or(GovernanceError::Overflow)?;
+        proposal.participating_tokens = 0;
+        // Voting opens once the proposer signs off on the transactions
+        proposal.status = ProposalStatus::Draft;
+        proposal.start_time = 0;
+        proposal.end_time = voting_period;
+        proposal.voting_completed_at = 0;
+        proposal.transaction_count = 0;
+        proposal.executed_transaction_count = 0;
         proposal.bump = *ctx.bumps.get("proposal").unwrap();
         
         // Update the realm proposal count
@@ -54,6 +78,55 @@ pub mod axiom_governance {
         Ok(())
     }
 
+    // Attach a transaction to be executed by the realm treasury if the proposal passes
+    pub fn insert_transaction(
+        ctx: Context<InsertTransaction>,
+        index: u16,
+        instructions: Vec<InstructionData>,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        
+        // Transactions are fixed once the proposer signs off so voters see the final payload
+        require!(
+            proposal.status == ProposalStatus::Draft,
+            GovernanceError::VotingAlreadyStarted
+        );
+        require!(index == proposal.transaction_count, GovernanceError::InvalidTransactionIndex);
+        require!(!instructions.is_empty(), GovernanceError::EmptyTransaction);
+        
+        let transaction = &mut ctx.accounts.proposal_transaction;
+        transaction.proposal = proposal.key();
+        transaction.index = index;
+        transaction.instructions = instructions;
+        transaction.executed_at = None;
+        transaction.bump = *ctx.bumps.get("proposal_transaction").unwrap();
+        
+        proposal.transaction_count = proposal.transaction_count.checked_add(1)
+            .ok_or(GovernanceError::Overflow)?;
+        
+        msg!("Transaction {} inserted into proposal '{}'", index, proposal.title);
+        Ok(())
+    }
+
+    // Fix the proposal's transactions and open voting for the voting period
+    pub fn sign_off_proposal(ctx: Context<SignOffProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(
+            proposal.status == ProposalStatus::Draft,
+            GovernanceError::VotingAlreadyStarted
+        );
+        
+        // A draft keeps its voting period in end_time until it is signed off
+        let voting_period = proposal.end_time;
+        proposal.status = ProposalStatus::Active;
+        proposal.start_time = Clock::get()?.unix_timestamp;
+        proposal.end_time = proposal.start_time.checked_add(voting_period)
+            .ok_or(GovernanceError::Overflow)?;
+        
+        msg!("Proposal '{}' signed off, voting ends at {}", proposal.title, proposal.end_time);
+        Ok(())
+    }
+
     // Cast a vote on a proposal
     pub fn cast_vote(ctx: Context<CastVote>, vote: Vote, amount: u64) -> Result<()> {
         let proposal = &mut ctx.accounts.proposal;
@@ -72,17 +145,15 @@ pub mod axiom_governance {
         );
         
         // Record the vote
-        match vote {
-            Vote::Yes => proposal.yes_votes = proposal.yes_votes.checked_add(amount)
-                .ok_or(GovernanceError::Overflow)?,
-            Vote::No => proposal.no_votes = proposal.no_votes.checked_add(amount)
-                .ok_or(GovernanceError::Overflow)?,
-            Vote::Abstain => proposal.abstain_votes = proposal.abstain_votes.checked_add(amount)
-                .ok_or(GovernanceError::Overflow)?,
-        }
+        proposal.add_vote(vote, amount, amount)?;
         
-        proposal.total_votes = proposal.total_votes.checked_add(amount)
-            .ok_or(GovernanceError::Overflow)?;
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.proposal = proposal.key();
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.vote = vote;
+        vote_record.token_amount = amount;
+        vote_record.voting_power = amount;
+        vote_record.bump = *ctx.bumps.get("vote_record").unwrap();
         
         // Transfer voting tokens from voter to proposal escrow
         let cpi_accounts = TransferChecked {
@@ -101,6 +172,62 @@ pub mod axiom_governance {
         Ok(())
     }
 
+    // Withdraw a vote while voting is still open and get the escrowed tokens back
+    pub fn relinquish_vote(ctx: Context<RelinquishVote>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let vote_record = &ctx.accounts.vote_record;
+        
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time < proposal.end_time,
+            GovernanceError::VotingPeriodEnded
+        );
+        
+        proposal.remove_vote(vote_record.vote, vote_record.voting_power, vote_record.token_amount)?;
+        
+        let amount = vote_record.token_amount;
+        return_escrowed_tokens(
+            proposal,
+            &ctx.accounts.proposal_escrow_account,
+            &ctx.accounts.voter_token_account,
+            &ctx.accounts.governing_token_mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+        
+        // The vote record is closed on return, so the voter can vote again
+        msg!("Vote relinquished: {:?} with {} tokens", vote_record.vote, amount);
+        Ok(())
+    }
+
+    // Return escrowed vote tokens once the proposal is no longer active
+    pub fn withdraw_vote_tokens(ctx: Context<WithdrawVoteTokens>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        
+        require!(
+            proposal.status != ProposalStatus::Active,
+            GovernanceError::ProposalStillActive
+        );
+        
+        let amount = ctx.accounts.vote_record.token_amount;
+        return_escrowed_tokens(
+            proposal,
+            &ctx.accounts.proposal_escrow_account,
+            &ctx.accounts.voter_token_account,
+            &ctx.accounts.governing_token_mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+        
+        msg!("Withdrew {} vote tokens from proposal '{}'", amount, proposal.title);
+        Ok(())
+    }
+
     // Finalize a proposal after voting period ends
     pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
         let proposal = &mut ctx.accounts.proposal;
@@ -118,18 +245,21 @@ pub mod axiom_governance {
             GovernanceError::ProposalNotActive
         );
         
-        // Determine the outcome
-        if proposal.yes_votes > proposal.no_votes {
+        // Determine the outcome against the realm's quorum and approval threshold
+        let config = &ctx.accounts.realm.config;
+        let supply = ctx.accounts.governing_token_mint.supply;
+        if proposal.has_quorum(config, supply) && proposal.is_approved(config) {
             proposal.status = ProposalStatus::Passed;
         } else {
             proposal.status = ProposalStatus::Failed;
         }
+        proposal.voting_completed_at = current_time;
         
         msg!("Proposal '{}' finalized with status: {:?}", proposal.title, proposal.status);
         Ok(())
     }
 
-    // Execute a passed proposal
+    // Execute a passed proposal that carries no transactions
     pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
         let proposal = &mut ctx.accounts.proposal;
         
@@ -139,14 +269,72 @@ pub mod axiom_governance {
             GovernanceError::ProposalNotPassed
         );
         
-        // In a real implementation, this would execute the proposal's actions
-        // For now, we'll just mark it as executed
+        // Proposals with transactions are executed through execute_transaction
+        require!(proposal.transaction_count == 0, GovernanceError::ProposalHasTransactions);
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= proposal.hold_up_ends_at(ctx.accounts.realm.config.hold_up_time)?,
+            GovernanceError::HoldUpTimeNotElapsed
+        );
+        
         proposal.status = ProposalStatus::Executed;
         
         msg!("Proposal '{}' executed", proposal.title);
         Ok(())
     }
 
+    // Execute one transaction of a passed proposal, signed by the realm native treasury
+    pub fn execute_transaction<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>) -> Result<()> {
+        let realm = &ctx.accounts.realm;
+        let proposal = &mut ctx.accounts.proposal;
+        let transaction = &mut ctx.accounts.proposal_transaction;
+        
+        require!(
+            proposal.status == ProposalStatus::Passed,
+            GovernanceError::ProposalNotPassed
+        );
+        require!(transaction.executed_at.is_none(), GovernanceError::TransactionAlreadyExecuted);
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= proposal.hold_up_ends_at(realm.config.hold_up_time)?,
+            GovernanceError::HoldUpTimeNotElapsed
+        );
+        
+        let realm_key = realm.key();
+        let treasury_seeds = &[
+            b"native-treasury".as_ref(),
+            realm_key.as_ref(),
+            &[realm.treasury_bump],
+        ];
+        let signer = &[&treasury_seeds[..]];
+        
+        // Every account referenced by the instructions, including the target
+        // programs, has to be passed in remaining_accounts
+        let mut account_infos = ctx.remaining_accounts.to_vec();
+        account_infos.push(ctx.accounts.native_treasury.to_account_info());
+        
+        // Mark the transaction executed and write it back before invoking, so an
+        // instruction calling back into this program cannot run it a second time
+        transaction.executed_at = Some(current_time);
+        proposal.executed_transaction_count = proposal.executed_transaction_count.checked_add(1)
+            .ok_or(GovernanceError::Overflow)?;
+        if proposal.executed_transaction_count == proposal.transaction_count {
+            proposal.status = ProposalStatus::Executed;
+        }
+        transaction.exit(ctx.program_id)?;
+        proposal.exit(ctx.program_id)?;
+        
+        for instruction_data in transaction.instructions.iter() {
+            let instruction = Instruction::from(instruction_data);
+            invoke_signed(&instruction, &account_infos, signer)?;
+        }
+        
+        msg!("Transaction {} of proposal '{}' executed", transaction.index, proposal.title);
+        Ok(())
+    }
+
     // Quadratic voting implementation
     pub fn cast_quadratic_vote(ctx: Context<CastQuadraticVote>, vote: Vote, amount: u64) -> Result<()> {
         let proposal = &mut ctx.accounts.proposal;
@@ -164,21 +352,23 @@ pub mod axiom_governance {
             GovernanceError::VotingPeriodEnded
         );
         
+        // Quadratic voting is one ballot per verified identity
+        verify_voter_identity(&ctx.accounts.voter_identity, &ctx.accounts.voter.key())?;
+        
         // Calculate quadratic voting power (square root of tokens)
-        let voting_power = (amount as f64).sqrt() as u64;
+        let voting_power = integer_sqrt(amount);
+        require!(voting_power > 0, GovernanceError::InsufficientVotingPower);
         
         // Record the vote with quadratic voting power
-        match vote {
-            Vote::Yes => proposal.yes_votes = proposal.yes_votes.checked_add(voting_power)
-                .ok_or(GovernanceError::Overflow)?,
-            Vote::No => proposal.no_votes = proposal.no_votes.checked_add(voting_power)
-                .ok_or(GovernanceError::Overflow)?,
-            Vote::Abstain => proposal.abstain_votes = proposal.abstain_votes.checked_add(voting_power)
-                .ok_or(GovernanceError::Overflow)?,
-        }
+        proposal.add_vote(vote, voting_power, amount)?;
         
-        proposal.total_votes = proposal.total_votes.checked_add(voting_power)
-            .ok_or(GovernanceError::Overflow)?;
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.proposal = proposal.key();
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.vote = vote;
+        vote_record.token_amount = amount;
+        vote_record.voting_power = voting_power;
+        vote_record.bump = *ctx.bumps.get("vote_record").unwrap();
         
         // Transfer voting tokens from voter to proposal escrow
         let cpi_accounts = TransferChecked {
@@ -196,9 +386,86 @@ pub mod axiom_governance {
         msg!("Quadratic vote cast: {:?} with {} tokens (voting power: {})", vote, amount, voting_power);
         Ok(())
     }
+
+    // Update the realm governance config, only callable from a passed proposal
+    pub fn set_governance_config(ctx: Context<SetGovernanceConfig>, config: GovernanceConfig) -> Result<()> {
+        config.validate()?;
+        
+        let realm = &mut ctx.accounts.realm;
+        realm.config = config;
+        
+        msg!("Governance config updated for realm '{}'", realm.name);
+        Ok(())
+    }
+}
+
+// Floor of the square root, computed with integer Newton iterations
+pub fn integer_sqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+    
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+// The voter must hold a PoHW attestation written by an authorized verifier, which
+// the voter cannot create for itself. It is a PDA derived from the holder's wallet,
+// so together with the per-voter VoteRecord each identity gets a single ballot per proposal.
+fn verify_voter_identity(identity: &AccountInfo, voter: &Pubkey) -> Result<()> {
+    require_keys_eq!(*identity.owner, axiom_pohw::ID, GovernanceError::InvalidIdentity);
+    let (schema, _) = Pubkey::find_program_address(&[b"pohw-schema"], &axiom_pohw::ID);
+    let (expected, _) = Pubkey::find_program_address(
+        &[b"pohw-attestation", schema.as_ref(), voter.as_ref()],
+        &axiom_pohw::ID,
+    );
+    require_keys_eq!(identity.key(), expected, GovernanceError::IdentityMismatch);
+    
+    let attestation = Account::<axiom_pohw::HumanWorkAttestation>::try_from(identity)?;
+    require_keys_eq!(attestation.axiom_id_holder, *voter, GovernanceError::IdentityMismatch);
+    // Attestations migrated from before the verifier set were self-reported
+    require!(attestation.verifier != Pubkey::default(), GovernanceError::InactiveIdentity);
+    Ok(())
+}
+
+// Transfer tokens out of the proposal escrow, signed by the proposal PDA
+fn return_escrowed_tokens<'info>(
+    proposal: &Account<'info, GovernanceProposal>,
+    proposal_escrow_account: &InterfaceAccount<'info, TokenAccount>,
+    voter_token_account: &InterfaceAccount<'info, TokenAccount>,
+    governing_token_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Program<'info, Token2022>,
+    amount: u64,
+) -> Result<()> {
+    let proposal_seeds = &[
+        b"governance-proposal".as_ref(),
+        proposal.realm.as_ref(),
+        proposal.title.as_bytes(),
+        proposal.proposer.as_ref(),
+        &[proposal.bump],
+    ];
+    let signer = &[&proposal_seeds[..]];
+    
+    let cpi_accounts = TransferChecked {
+        from: proposal_escrow_account.to_account_info(),
+        to: voter_token_account.to_account_info(),
+        authority: proposal.to_account_info(),
+        mint: governing_token_mint.to_account_info(),
+    };
+    
+    let cpi_program = token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    
+    transfer_checked(cpi_ctx, amount, governing_token_mint.decimals)
 }
 
 #[derive(Accounts)]
+#[instruction(name: String)]
 pub struct InitializeRealm<'info> {
     #[account(
         init,
@@ -209,6 +476,15 @@ pub struct InitializeRealm<'info> {
     )]
     pub realm: Account<'info, GovernanceRealm>,
     
+    /// CHECK: System-owned PDA that signs proposal transactions for the realm
+    #[account(
+        seeds = [b"native-treasury", realm.key().as_ref()],
+        bump
+    )]
+    pub native_treasury: AccountInfo<'info>,
+    
+    pub governing_token_mint: InterfaceAccount<'info, Mint>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -239,14 +515,80 @@ pub struct CreateProposal<'info> {
     )]
     pub realm: Account<'info, GovernanceRealm>,
     
+    #[account(
+        token::mint = realm.governing_token_mint,
+        token::authority = proposer,
+    )]
+    pub proposer_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(index: u16, instructions: Vec<InstructionData>)]
+pub struct InsertTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"governance-proposal", 
+            proposal.realm.as_ref(), 
+            proposal.title.as_bytes(),
+            proposal.proposer.as_ref()
+        ],
+        bump = proposal.bump,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = ProposalTransaction::space(&instructions),
+        seeds = [
+            b"proposal-transaction",
+            proposal.key().as_ref(),
+            &index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal_transaction: Account<'info, ProposalTransaction>,
+    
     #[account(mut)]
     pub proposer: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct SignOffProposal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"governance-proposal", 
+            proposal.realm.as_ref(), 
+            proposal.title.as_bytes(),
+            proposal.proposer.as_ref()
+        ],
+        bump = proposal.bump,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+    
+    pub proposer: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct CastVote<'info> {
+    #[account(
+        seeds = [b"governance-realm", realm.name.as_bytes()],
+        bump = realm.bump,
+        has_one = governing_token_mint
+    )]
+    pub realm: Account<'info, GovernanceRealm>,
+    
     #[account(
         mut,
         seeds = [
@@ -255,7 +597,8 @@ pub struct CastVote<'info> {
             proposal.title.as_bytes(),
             proposal.proposer.as_ref()
         ],
-        bump = proposal.bump
+        bump = proposal.bump,
+        has_one = realm
     )]
     pub proposal: Account<'info, GovernanceProposal>,
     
@@ -271,9 +614,21 @@ pub struct CastVote<'info> {
         payer = voter,
         token::mint = governing_token_mint,
         token::authority = proposal,
+        seeds = [b"proposal-escrow", proposal.key().as_ref()],
+        bump
     )]
     pub proposal_escrow_account: InterfaceAccount<'info, TokenAccount>,
     
+    // One vote record per voter and proposal prevents double voting
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote-record", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
     pub governing_token_mint: InterfaceAccount<'info, Mint>,
     
     #[account(mut)]
@@ -284,7 +639,7 @@ pub struct CastVote<'info> {
 }
 
 #[derive(Accounts)]
-pub struct FinalizeProposal<'info> {
+pub struct RelinquishVote<'info> {
     #[account(
         mut,
         seeds = [
@@ -297,32 +652,43 @@ pub struct FinalizeProposal<'info> {
     )]
     pub proposal: Account<'info, GovernanceProposal>,
     
-    /// CHECK: This account can be any valid pubkey
-    pub authority: AccountInfo<'info>,
-}
-
-#[derive(Accounts)]
-pub struct ExecuteProposal<'info> {
     #[account(
         mut,
-        seeds = [
-            b"governance-proposal", 
-            proposal.realm.as_ref(), 
-            proposal.title.as_bytes(),
-            proposal.proposer.as_ref()
-        ],
-        bump = proposal.bump
+        close = voter,
+        seeds = [b"vote-record", proposal.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        has_one = proposal,
+        has_one = voter
     )]
-    pub proposal: Account<'info, GovernanceProposal>,
+    pub vote_record: Account<'info, VoteRecord>,
     
-    /// CHECK: This account can be any valid pubkey
-    pub authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = governing_token_mint,
+        token::authority = voter,
+    )]
+    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = governing_token_mint,
+        token::authority = proposal,
+        seeds = [b"proposal-escrow", proposal.key().as_ref()],
+        bump
+    )]
+    pub proposal_escrow_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub governing_token_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
 }
 
 #[derive(Accounts)]
-pub struct CastQuadraticVote<'info> {
+pub struct WithdrawVoteTokens<'info> {
     #[account(
-        mut,
         seeds = [
             b"governance-proposal", 
             proposal.realm.as_ref(), 
@@ -333,6 +699,16 @@ pub struct CastQuadraticVote<'info> {
     )]
     pub proposal: Account<'info, GovernanceProposal>,
     
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"vote-record", proposal.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        has_one = proposal,
+        has_one = voter
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
     #[account(
         mut,
         token::mint = governing_token_mint,
@@ -341,10 +717,11 @@ pub struct CastQuadraticVote<'info> {
     pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
-        init_if_needed,
-        payer = voter,
+        mut,
         token::mint = governing_token_mint,
         token::authority = proposal,
+        seeds = [b"proposal-escrow", proposal.key().as_ref()],
+        bump
     )]
     pub proposal_escrow_account: InterfaceAccount<'info, TokenAccount>,
     
@@ -354,325 +731,69 @@ pub struct CastQuadraticVote<'info> {
     pub voter: Signer<'info>,
     
     pub token_program: Program<'info, Token2022>,
-    pub system_program: Program<'info, System>,
 }
 
-// Governance realm account
-#[account]
-#[derive(InitSpace)]
-pub struct GovernanceRealm {
-    pub authority: Pubkey,
-    #[max_len(100)]
-    pub name: String,
-    pub proposal_count: u64,
-    pub bump: u8,
-}
-
-// Governance proposal account
-#[account]
-#[derive(InitSpace)]
-pub struct GovernanceProposal {
-    pub realm: Pubkey,
-    pub proposer: Pubkey,
-    #[max_len(100)]
-    pub title: String,
-    #[max_len(1000)]
-    pub description: String,
-    pub yes_votes: u64,
-    pub no_votes: u64,
-    pub abstain_votes: u64,
-    pub total_votes: u64,
-    pub status: ProposalStatus,
-    pub start_time: i64,
-    pub end_time: i64,
-    pub bump: u8,
-}
-
-// Vote options
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum Vote {
-    Yes,
-    No,
-    Abstain,
-}
-
-// Proposal status
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum ProposalStatus {
-    Active,
-    Passed,
-    Failed,
-    Executed,
-}
-
-#[error_code]
-pub enum GovernanceError {
-    #[msg("Arithmetic overflow")]
-    Overflow,
-    
-    #[msg("Proposal is not active")]
-    ProposalNotActive,
-    
-    #[msg("Voting period has ended")]
-    VotingPeriodEnded,
-    
-    #[msg("Voting period has not ended yet")]
-    VotingPeriodNotEnded,
-    
-    #[msg("Proposal has not passed")]
-    ProposalNotPassed,
-}
-}
-
-use anchor_lang::prelude::*;
-use anchor_spl::{
-    token_2022::Token2022,
-    token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
-};
-
-// This is our new Program ID. Anchor will update this for us later.
-declare_id!("7sKxhfHdQgjWBuoztEYonKepba2zGcN2QtWowCmAfWzD");
-
-#[program]
-pub mod axiom_governance {
-    use super::*;
-
-    // Initialize the governance realm
-    pub fn initialize_realm(ctx: Context<InitializeRealm>, name: String) -> Result<()> {
-        let realm = &mut ctx.accounts.realm;
-        realm.authority = ctx.accounts.authority.key();
-        realm.name = name;
-        realm.proposal_count = 0;
-        realm.bump = *ctx.bumps.get("realm").unwrap();
-        
-        msg!("Governance realm '{}' initialized", realm.name);
-        Ok(())
-    }
-
-    // Create a new governance proposal
-    pub fn create_proposal(
-        ctx: Context<CreateProposal>,
-        title: String,
-        description: String,
-        voting_period: i64, // in seconds
-    ) -> Result<()> {
-        let proposal = &mut ctx.accounts.proposal;
-        proposal.realm = ctx.accounts.realm.key();
-        proposal.proposer = ctx.accounts.proposer.key();
-        proposal.title = title;
-        proposal.description = description;
-        proposal.yes_votes = 0;
-        proposal.no_votes = 0;
-        proposal.abstain_votes = 0;
-        proposal.total_votes = 0;
-        proposal.status = ProposalStatus::Active;
-        proposal.start_time = Clock::get()?.unix_timestamp;
-        proposal.end_time = proposal.start_time.checked_add(voting_period)
-            .ok_or(GovernanceError::Overflow)?;
-        proposal.bump = *ctx.bumps.get("proposal").unwrap();
-        
-        // Update the realm proposal count
-        let realm = &mut ctx.accounts.realm;
-        realm.proposal_count = realm.proposal_count.checked_add(1)
-            .ok_or(GovernanceError::Overflow)?;
-        
-        msg!("Proposal '{}' created", proposal.title);
-        Ok(())
-    }
-
-    // Cast a vote on a proposal
-    pub fn cast_vote(ctx: Context<CastVote>, vote: Vote, amount: u64) -> Result<()> {
-        let proposal = &mut ctx.accounts.proposal;
-        
-        // Check if proposal is active
-        require!(
-            proposal.status == ProposalStatus::Active,
-            GovernanceError::ProposalNotActive
-        );
-        
-        // Check if voting period has ended
-        let current_time = Clock::get()?.unix_timestamp;
-        require!(
-            current_time < proposal.end_time,
-            GovernanceError::VotingPeriodEnded
-        );
-        
-        // Record the vote
-        match vote {
-            Vote::Yes => proposal.yes_votes = proposal.yes_votes.checked_add(amount)
-                .ok_or(GovernanceError::Overflow)?,
-            Vote::No => proposal.no_votes = proposal.no_votes.checked_add(amount)
-                .ok_or(GovernanceError::Overflow)?,
-            Vote::Abstain => proposal.abstain_votes = proposal.abstain_votes.checked_add(amount)
-                .ok_or(GovernanceError::Overflow)?,
-        }
-        
-        proposal.total_votes = proposal.total_votes.checked_add(amount)
-            .ok_or(GovernanceError::Overflow)?;
-        
-        // Transfer voting tokens from voter to proposal escrow
-        let cpi_accounts = TransferChecked {
-            from: ctx.accounts.voter_token_account.to_account_info(),
-            to: ctx.accounts.proposal_escrow_account.to_account_info(),
-            authority: ctx.accounts.voter.to_account_info(),
-            mint: ctx.accounts.governing_token_mint.to_account_info(),
-        };
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        
-        transfer_checked(cpi_ctx, amount, ctx.accounts.governing_token_mint.decimals)?;
-        
-        msg!("Vote cast: {:?} with {} tokens", vote, amount);
-        Ok(())
-    }
-
-    // Finalize a proposal after voting period ends
-    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
-        let proposal = &mut ctx.accounts.proposal;
-        
-        // Check if voting period has ended
-        let current_time = Clock::get()?.unix_timestamp;
-        require!(
-            current_time >= proposal.end_time,
-            GovernanceError::VotingPeriodNotEnded
-        );
-        
-        // Check if proposal is active
-        require!(
-            proposal.status == ProposalStatus::Active,
-            GovernanceError::ProposalNotActive
-        );
-        
-        // Determine the outcome
-        if proposal.yes_votes > proposal.no_votes {
-            proposal.status = ProposalStatus::Passed;
-        } else {
-            proposal.status = ProposalStatus::Failed;
-        }
-        
-        msg!("Proposal '{}' finalized with status: {:?}", proposal.title, proposal.status);
-        Ok(())
-    }
-
-    // Execute a passed proposal
-    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
-        let proposal = &mut ctx.accounts.proposal;
-        
-        // Check if proposal has passed
-        require!(
-            proposal.status == ProposalStatus::Passed,
-            GovernanceError::ProposalNotPassed
-        );
-        
-        // In a real implementation, this would execute the proposal's actions
-        // For now, we'll just mark it as executed
-        proposal.status = ProposalStatus::Executed;
-        
-        msg!("Proposal '{}' executed", proposal.title);
-        Ok(())
-    }
-
-    // Quadratic voting implementation
-    pub fn cast_quadratic_vote(ctx: Context<CastQuadraticVote>, vote: Vote, amount: u64) -> Result<()> {
-        let proposal = &mut ctx.accounts.proposal;
-        
-        // Check if proposal is active
-        require!(
-            proposal.status == ProposalStatus::Active,
-            GovernanceError::ProposalNotActive
-        );
-        
-        // Check if voting period has ended
-        let current_time = Clock::get()?.unix_timestamp;
-        require!(
-            current_time < proposal.end_time,
-            GovernanceError::VotingPeriodEnded
-        );
-        
-        // Calculate quadratic voting power (square root of tokens)
-        let voting_power = (amount as f64).sqrt() as u64;
-        
-        // Record the vote with quadratic voting power
-        match vote {
-            Vote::Yes => proposal.yes_votes = proposal.yes_votes.checked_add(voting_power)
-                .ok_or(GovernanceError::Overflow)?,
-            Vote::No => proposal.no_votes = proposal.no_votes.checked_add(voting_power)
-                .ok_or(GovernanceError::Overflow)?,
-            Vote::Abstain => proposal.abstain_votes = proposal.abstain_votes.checked_add(voting_power)
-                .ok_or(GovernanceError::Overflow)?,
-        }
-        
-        proposal.total_votes = proposal.total_votes.checked_add(voting_power)
-            .ok_or(GovernanceError::Overflow)?;
-        
-        // Transfer voting tokens from voter to proposal escrow
-        let cpi_accounts = TransferChecked {
-            from: ctx.accounts.voter_token_account.to_account_info(),
-            to: ctx.accounts.proposal_escrow_account.to_account_info(),
-            authority: ctx.accounts.voter.to_account_info(),
-            mint: ctx.accounts.governing_token_mint.to_account_info(),
-        };
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        
-        transfer_checked(cpi_ctx, amount, ctx.accounts.governing_token_mint.decimals)?;
-        
-        msg!("Quadratic vote cast: {:?} with {} tokens (voting power: {})", vote, amount, voting_power);
-        Ok(())
-    }
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    #[account(
+        seeds = [b"governance-realm", realm.name.as_bytes()],
+        bump = realm.bump,
+        has_one = governing_token_mint
+    )]
+    pub realm: Account<'info, GovernanceRealm>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"governance-proposal", 
+            proposal.realm.as_ref(), 
+            proposal.title.as_bytes(),
+            proposal.proposer.as_ref()
+        ],
+        bump = proposal.bump,
+        has_one = realm
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+    
+    pub governing_token_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: This account can be any valid pubkey
+    pub authority: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeRealm<'info> {
+pub struct ExecuteProposal<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + GovernanceRealm::INIT_SPACE,
-        seeds = [b"governance-realm", name.as_bytes()],
-        bump
+        seeds = [b"governance-realm", realm.name.as_bytes()],
+        bump = realm.bump
     )]
     pub realm: Account<'info, GovernanceRealm>,
     
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-#[instruction(title: String)]
-pub struct CreateProposal<'info> {
     #[account(
-        init,
-        payer = proposer,
-        space = 8 + GovernanceProposal::INIT_SPACE,
+        mut,
         seeds = [
             b"governance-proposal", 
-            realm.key().as_ref(), 
-            title.as_bytes(),
-            proposer.key().as_ref()
+            proposal.realm.as_ref(), 
+            proposal.title.as_bytes(),
+            proposal.proposer.as_ref()
         ],
-        bump
+        bump = proposal.bump,
+        has_one = realm
     )]
     pub proposal: Account<'info, GovernanceProposal>,
     
+    /// CHECK: This account can be any valid pubkey
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteTransaction<'info> {
     #[account(
-        mut,
         seeds = [b"governance-realm", realm.name.as_bytes()],
         bump = realm.bump
     )]
     pub realm: Account<'info, GovernanceRealm>,
     
-    #[account(mut)]
-    pub proposer: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct CastVote<'info> {
     #[account(
         mut,
         seeds = [
@@ -681,72 +802,44 @@ pub struct CastVote<'info> {
             proposal.title.as_bytes(),
             proposal.proposer.as_ref()
         ],
-        bump = proposal.bump
+        bump = proposal.bump,
+        has_one = realm
     )]
     pub proposal: Account<'info, GovernanceProposal>,
     
-    #[account(
-        mut,
-        token::mint = governing_token_mint,
-        token::authority = voter,
-    )]
-    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
-    
-    #[account(
-        init_if_needed,
-        payer = voter,
-        token::mint = governing_token_mint,
-        token::authority = proposal,
-    )]
-    pub proposal_escrow_account: InterfaceAccount<'info, TokenAccount>,
-    
-    pub governing_token_mint: InterfaceAccount<'info, Mint>,
-    
-    #[account(mut)]
-    pub voter: Signer<'info>,
-    
-    pub token_program: Program<'info, Token2022>,
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct FinalizeProposal<'info> {
     #[account(
         mut,
         seeds = [
-            b"governance-proposal", 
-            proposal.realm.as_ref(), 
-            proposal.title.as_bytes(),
-            proposal.proposer.as_ref()
+            b"proposal-transaction",
+            proposal.key().as_ref(),
+            &proposal_transaction.index.to_le_bytes()
         ],
-        bump = proposal.bump
+        bump = proposal_transaction.bump,
+        has_one = proposal
     )]
-    pub proposal: Account<'info, GovernanceProposal>,
+    pub proposal_transaction: Account<'info, ProposalTransaction>,
     
-    /// CHECK: This account can be any valid pubkey
-    pub authority: AccountInfo<'info>,
-}
-
-#[derive(Accounts)]
-pub struct ExecuteProposal<'info> {
+    /// CHECK: System-owned PDA that signs proposal transactions for the realm
     #[account(
         mut,
-        seeds = [
-            b"governance-proposal", 
-            proposal.realm.as_ref(), 
-            proposal.title.as_bytes(),
-            proposal.proposer.as_ref()
-        ],
-        bump = proposal.bump
+        seeds = [b"native-treasury", realm.key().as_ref()],
+        bump = realm.treasury_bump
     )]
-    pub proposal: Account<'info, GovernanceProposal>,
+    pub native_treasury: AccountInfo<'info>,
     
-    /// CHECK: This account can be any valid pubkey
-    pub authority: AccountInfo<'info>,
+    // Anyone can execute a passed proposal once the hold-up time has elapsed
+    pub executor: Signer<'info>,
 }
 
 #[derive(Accounts)]
 pub struct CastQuadraticVote<'info> {
+    #[account(
+        seeds = [b"governance-realm", realm.name.as_bytes()],
+        bump = realm.bump,
+        has_one = governing_token_mint
+    )]
+    pub realm: Account<'info, GovernanceRealm>,
+    
     #[account(
         mut,
         seeds = [
@@ -755,7 +848,8 @@ pub struct CastQuadraticVote<'info> {
             proposal.title.as_bytes(),
             proposal.proposer.as_ref()
         ],
-        bump = proposal.bump
+        bump = proposal.bump,
+        has_one = realm
     )]
     pub proposal: Account<'info, GovernanceProposal>,
     
@@ -771,11 +865,26 @@ pub struct CastQuadraticVote<'info> {
         payer = voter,
         token::mint = governing_token_mint,
         token::authority = proposal,
+        seeds = [b"proposal-escrow", proposal.key().as_ref()],
+        bump
     )]
     pub proposal_escrow_account: InterfaceAccount<'info, TokenAccount>,
     
+    // One vote record per voter and proposal prevents double voting
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote-record", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
     pub governing_token_mint: InterfaceAccount<'info, Mint>,
     
+    /// CHECK: The voter's HumanWorkAttestation, validated in the handler
+    pub voter_identity: AccountInfo<'info>,
+    
     #[account(mut)]
     pub voter: Signer<'info>,
     
@@ -783,6 +892,23 @@ pub struct CastQuadraticVote<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct SetGovernanceConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance-realm", realm.name.as_bytes()],
+        bump = realm.bump
+    )]
+    pub realm: Account<'info, GovernanceRealm>,
+    
+    // Only the realm treasury can sign, which happens through execute_transaction
+    #[account(
+        seeds = [b"native-treasury", realm.key().as_ref()],
+        bump = realm.treasury_bump
+    )]
+    pub native_treasury: Signer<'info>,
+}
+
 // Governance realm account
 #[account]
 #[derive(InitSpace)]
@@ -790,10 +916,48 @@ pub struct GovernanceRealm {
     pub authority: Pubkey,
     #[max_len(100)]
     pub name: String,
+    pub governing_token_mint: Pubkey,
     pub proposal_count: u64,
+    pub config: GovernanceConfig,
+    pub treasury_bump: u8,
     pub bump: u8,
 }
 
+// Rules proposals in a realm are created and decided by
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct GovernanceConfig {
+    // Share of the governing mint supply that has to take part, in basis points
+    pub min_quorum_bps: u16,
+    // Share of yes votes out of yes and no votes needed to pass, in basis points
+    pub approval_threshold_bps: u16,
+    pub min_voting_period: i64,
+    pub max_voting_period: i64,
+    pub min_tokens_to_create_proposal: u64,
+    // Seconds a passed proposal waits before its transactions can execute
+    pub hold_up_time: i64,
+}
+
+impl GovernanceConfig {
+    pub const MAX_BPS: u16 = 10_000;
+    
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_quorum_bps <= Self::MAX_BPS,
+            GovernanceError::InvalidGovernanceConfig
+        );
+        require!(
+            self.approval_threshold_bps > 0 && self.approval_threshold_bps <= Self::MAX_BPS,
+            GovernanceError::InvalidGovernanceConfig
+        );
+        require!(
+            self.min_voting_period > 0 && self.min_voting_period <= self.max_voting_period,
+            GovernanceError::InvalidGovernanceConfig
+        );
+        require!(self.hold_up_time >= 0, GovernanceError::InvalidHoldUpTime);
+        Ok(())
+    }
+}
+
 // Governance proposal account
 #[account]
 #[derive(InitSpace)]
@@ -808,14 +972,137 @@ pub struct GovernanceProposal {
     pub no_votes: u64,
     pub abstain_votes: u64,
     pub total_votes: u64,
+    // Governing tokens escrowed by voters, used for quorum
+    pub participating_tokens: u64,
     pub status: ProposalStatus,
     pub start_time: i64,
     pub end_time: i64,
+    pub voting_completed_at: i64,
+    pub transaction_count: u16,
+    pub executed_transaction_count: u16,
     pub bump: u8,
 }
 
+impl GovernanceProposal {
+    pub fn add_vote(&mut self, vote: Vote, weight: u64, tokens: u64) -> Result<()> {
+        let tally = match vote {
+            Vote::Yes => &mut self.yes_votes,
+            Vote::No => &mut self.no_votes,
+            Vote::Abstain => &mut self.abstain_votes,
+        };
+        *tally = tally.checked_add(weight).ok_or(GovernanceError::Overflow)?;
+        
+        self.total_votes = self.total_votes.checked_add(weight)
+            .ok_or(GovernanceError::Overflow)?;
+        self.participating_tokens = self.participating_tokens.checked_add(tokens)
+            .ok_or(GovernanceError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn remove_vote(&mut self, vote: Vote, weight: u64, tokens: u64) -> Result<()> {
+        let tally = match vote {
+            Vote::Yes => &mut self.yes_votes,
+            Vote::No => &mut self.no_votes,
+            Vote::Abstain => &mut self.abstain_votes,
+        };
+        *tally = tally.checked_sub(weight).ok_or(GovernanceError::Overflow)?;
+        
+        self.total_votes = self.total_votes.checked_sub(weight)
+            .ok_or(GovernanceError::Overflow)?;
+        self.participating_tokens = self.participating_tokens.checked_sub(tokens)
+            .ok_or(GovernanceError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn has_quorum(&self, config: &GovernanceConfig, supply: u64) -> bool {
+        // Widened to u128 so the products cannot overflow
+        (self.participating_tokens as u128) * (GovernanceConfig::MAX_BPS as u128)
+            >= (supply as u128) * (config.min_quorum_bps as u128)
+    }
+    
+    pub fn is_approved(&self, config: &GovernanceConfig) -> bool {
+        let decided_votes = (self.yes_votes as u128) + (self.no_votes as u128);
+        self.yes_votes > 0
+            && (self.yes_votes as u128) * (GovernanceConfig::MAX_BPS as u128)
+                >= decided_votes * (config.approval_threshold_bps as u128)
+    }
+    
+    pub fn hold_up_ends_at(&self, hold_up_time: i64) -> Result<i64> {
+        self.voting_completed_at.checked_add(hold_up_time)
+            .ok_or(error!(GovernanceError::Overflow))
+    }
+}
+
+// A voter's ballot on a proposal and the tokens it escrowed
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub vote: Vote,
+    pub token_amount: u64,
+    pub voting_power: u64,
+    pub bump: u8,
+}
+
+// Instructions executed by the realm native treasury once a proposal passes
+#[account]
+pub struct ProposalTransaction {
+    pub proposal: Pubkey,
+    pub index: u16,
+    pub instructions: Vec<InstructionData>,
+    pub executed_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl ProposalTransaction {
+    pub fn space(instructions: &[InstructionData]) -> usize {
+        8 + // discriminator
+        32 + // proposal
+        2 + // index
+        4 + instructions.iter().map(InstructionData::space).sum::<usize>() +
+        (1 + 8) + // executed_at
+        1 // bump
+    }
+}
+
+// Serialized form of a Solana instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InstructionData {
+    pub program_id: Pubkey,
+    pub accounts: Vec<AccountMetaData>,
+    pub data: Vec<u8>,
+}
+
+impl InstructionData {
+    pub fn space(&self) -> usize {
+        32 + (4 + self.accounts.len() * AccountMetaData::INIT_SPACE) + (4 + self.data.len())
+    }
+}
+
+impl From<&InstructionData> for Instruction {
+    fn from(instruction: &InstructionData) -> Self {
+        Instruction {
+            program_id: instruction.program_id,
+            accounts: instruction.accounts.iter().map(|meta| AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            }).collect(),
+            data: instruction.data.clone(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct AccountMetaData {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
 // Vote options
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
 pub enum Vote {
     Yes,
     No,
@@ -823,8 +1110,9 @@ pub enum Vote {
 }
 
 // Proposal status
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
 pub enum ProposalStatus {
+    Draft,                          // Transactions can still be inserted, voting has not opened
     Active,
     Passed,
     Failed,
@@ -847,4 +1135,49 @@ pub enum GovernanceError {
     
     #[msg("Proposal has not passed")]
     ProposalNotPassed,
-}
\ No newline at end of file
+    
+    #[msg("Hold-up time cannot be negative")]
+    InvalidHoldUpTime,
+    
+    #[msg("Proposal has already been signed off")]
+    VotingAlreadyStarted,
+    
+    #[msg("Transaction index must follow the last inserted transaction")]
+    InvalidTransactionIndex,
+    
+    #[msg("Transaction has no instructions")]
+    EmptyTransaction,
+    
+    #[msg("Proposal transactions must be executed with execute_transaction")]
+    ProposalHasTransactions,
+    
+    #[msg("Hold-up time has not elapsed yet")]
+    HoldUpTimeNotElapsed,
+    
+    #[msg("Transaction has already been executed")]
+    TransactionAlreadyExecuted,
+    
+    #[msg("Proposal is still active")]
+    ProposalStillActive,
+    
+    #[msg("Invalid governance config")]
+    InvalidGovernanceConfig,
+    
+    #[msg("Voting period is outside the realm's allowed range")]
+    InvalidVotingPeriod,
+    
+    #[msg("Not enough governing tokens to create a proposal")]
+    NotEnoughTokensToPropose,
+    
+    #[msg("Vote amount gives no voting power")]
+    InsufficientVotingPower,
+    
+    #[msg("Voter identity must be a PoHW attestation")]
+    InvalidIdentity,
+    
+    #[msg("Voter identity belongs to a different wallet")]
+    IdentityMismatch,
+    
+    #[msg("Voter identity was not written by a verifier")]
+    InactiveIdentity,
+}
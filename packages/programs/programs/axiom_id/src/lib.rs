@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::clock::Clock;
 use anchor_spl::{
+    associated_token::{AssociatedToken, get_associated_token_address_with_program_id},
     token_2022::Token2022,
     token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
 };
+use agent_soul_factory::program::AgentSoulFactory;
+use axiom_attestations::{AttestationConfig, Reputation};
 
 // This is our new Program ID. Anchor will update this for us later.
 declare_id!("5E7eosX9X34CWCeGpw2C4ua2JRYTZqZ8MsFkxj3y6T7C");
@@ -33,10 +36,56 @@ pub mod axiom_id {
             AxiomAgentError::SoulAlreadyMinted
         );
         
-        // In a real implementation, we would perform CPIs to the Token-2022 program
-        // to create a non-transferable mint and mint the soul token to the agent's ATA
+        // The mint has to be a fresh, indivisible soul mint controlled by the factory
+        let soul_mint = &ctx.accounts.soul_mint;
+        require!(
+            agent_soul_factory::is_soul_mint(&soul_mint.to_account_info())?,
+            AxiomAgentError::InvalidSoulMint
+        );
+        require!(soul_mint.decimals == 0, AxiomAgentError::InvalidSoulMint);
+        require!(soul_mint.supply == 0, AxiomAgentError::InvalidSoulMint);
+        require!(
+            soul_mint.mint_authority == Some(ctx.accounts.soul_factory_authority.key()).into(),
+            AxiomAgentError::InvalidSoulMint
+        );
+        
+        // The soul is held by the agent PDA's associated token account
+        let expected_token_account = get_associated_token_address_with_program_id(
+            &ctx.accounts.agent_pda.key(),
+            &soul_mint.key(),
+            &ctx.accounts.token_program.key(),
+        );
+        require!(
+            ctx.accounts.agent_token_account.key() == expected_token_account,
+            AxiomAgentError::InvalidSoulTokenAccount
+        );
+        
+        // Mint exactly one soul token through the factory, which holds the mint authority
+        let cpi_accounts = agent_soul_factory::cpi::accounts::CreateSoulBoundToken {
+            mint: ctx.accounts.soul_mint.to_account_info(),
+            soul_mint_record: ctx.accounts.soul_mint_record.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            token_account: ctx.accounts.agent_token_account.to_account_info(),
+            agent_soul_factory: ctx.accounts.soul_factory_authority.to_account_info(),
+            recipient: ctx.accounts.agent_pda.to_account_info(),
+            payer: ctx.accounts.authority.to_account_info(),
+            payer_token_account: ctx.accounts.payer_token_account.to_account_info(),
+            fee_vault: ctx.accounts.fee_vault.to_account_info(),
+            axiom_token_mint: ctx.accounts.axiom_token_mint.to_account_info(),
+            fee_config: ctx.accounts.fee_config.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        
+        let cpi_program = ctx.accounts.soul_factory_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        
+        agent_soul_factory::cpi::create_soul_bound_token(cpi_ctx)?;
+        
+        ctx.accounts.soul_mint.reload()?;
+        require!(ctx.accounts.soul_mint.supply == 1, AxiomAgentError::InvalidSoulMint);
         
-        // For now, we'll just update the agent metadata to point to the soul mint
         let agent_metadata = &mut ctx.accounts.agent_metadata;
         agent_metadata.soul_mint = ctx.accounts.soul_mint.key();
         
@@ -149,49 +198,134 @@ pub mod axiom_id {
         Ok(())
     }
 
-    // New function to update reputation
-    pub fn update_reputation(ctx: Context<UpdateReputation>, reputation_change: i64) -> Result<()> {
-        let identity_account = &mut ctx.accounts.identity_account;
+    // Copy the identity owner's reputation from the attestation ledger, which is the
+    // only place reputation changes. Anyone can call this to refresh the cached value.
+    pub fn sync_reputation(ctx: Context<SyncReputation>) -> Result<()> {
+        let score = ctx.accounts.reputation.decayed_score(
+            Clock::get()?.unix_timestamp,
+            ctx.accounts.attestation_config.reputation_half_life,
+        );
         
-        // Update reputation with overflow protection
-        if reputation_change >= 0 {
-            identity_account.reputation = identity_account.reputation.checked_add(reputation_change as u64)
-                .ok_or(error!(AxiomAgentError::Overflow))?;
-        } else {
-            let abs_change = reputation_change.abs() as u64;
-            identity_account.reputation = identity_account.reputation.checked_sub(abs_change)
-                .unwrap_or(0); // Don't go below 0
-        }
+        // Negative reputation is cached as 0
+        let identity_account = &mut ctx.accounts.identity_account;
+        identity_account.reputation = score.max(0) as u64;
 
         msg!("Updated reputation for identity: {} to {}", identity_account.key(), identity_account.reputation);
         Ok(())
     }
     
     // New function to integrate with Cryptid DID for agent sovereignty
-    pub fn create_cryptid_did(ctx: Context<CreateCryptidDID>, did_document: String) -> Result<()> {
-        let agent_metadata = &mut ctx.accounts.agent_metadata;
+    pub fn create_cryptid_did(ctx: Context<CreateCryptidDID>) -> Result<()> {
+        let agent_metadata = &ctx.accounts.agent_metadata;
+        let controller = ctx.accounts.authority.key();
+        
+        // The DID key is the initial controller and authentication method
+        let did_document = &mut ctx.accounts.did_document;
+        did_document.did = agent_metadata.did;
+        did_document.agent_metadata = agent_metadata.key();
+        did_document.controllers = vec![controller];
+        did_document.verification_methods = vec![VerificationMethod {
+            id: "default".to_string(),
+            key: controller,
+            flags: VerificationMethod::AUTHENTICATION | VerificationMethod::CAPABILITY_INVOCATION,
+        }];
+        did_document.services = Vec::new();
+        did_document.updated_at = Clock::get()?.unix_timestamp;
+        did_document.bump = *ctx.bumps.get("did_document").unwrap();
+        
+        msg!("Cryptid DID document created for agent: {}", agent_metadata.did);
+        
+        Ok(())
+    }
+    
+    // Add or remove controllers, verification methods and service endpoints
+    pub fn update_did_document(ctx: Context<UpdateDidDocument>, update: DidDocumentUpdate) -> Result<()> {
+        let did_document = &mut ctx.accounts.did_document;
         
-        // In a full implementation, this would:
-        // 1. Create a Cryptid DID document on-chain
-        // 2. Associate the DID with the agent's identity
-        // 3. Set up key rotation capabilities
+        match update {
+            DidDocumentUpdate::AddController(controller) => {
+                require!(!did_document.is_controller(&controller), AxiomAgentError::DuplicateEntry);
+                require!(
+                    did_document.controllers.len() < DidDocument::MAX_CONTROLLERS,
+                    AxiomAgentError::DidDocumentFull
+                );
+                did_document.controllers.push(controller);
+            }
+            DidDocumentUpdate::RemoveController(controller) => {
+                // A DID without controllers could never be updated again
+                require!(did_document.controllers.len() > 1, AxiomAgentError::LastController);
+                let index = did_document.controllers.iter().position(|c| *c == controller)
+                    .ok_or(AxiomAgentError::EntryNotFound)?;
+                did_document.controllers.remove(index);
+            }
+            DidDocumentUpdate::AddVerificationMethod(method) => {
+                require!(method.id.len() <= VerificationMethod::MAX_ID_LENGTH, AxiomAgentError::EntryTooLong);
+                require!(
+                    !did_document.verification_methods.iter().any(|m| m.id == method.id),
+                    AxiomAgentError::DuplicateEntry
+                );
+                require!(
+                    did_document.verification_methods.len() < DidDocument::MAX_VERIFICATION_METHODS,
+                    AxiomAgentError::DidDocumentFull
+                );
+                did_document.verification_methods.push(method);
+            }
+            DidDocumentUpdate::RemoveVerificationMethod(id) => {
+                let index = did_document.verification_methods.iter().position(|m| m.id == id)
+                    .ok_or(AxiomAgentError::EntryNotFound)?;
+                did_document.verification_methods.remove(index);
+            }
+            DidDocumentUpdate::AddService(service) => {
+                require!(
+                    service.id.len() <= ServiceEndpoint::MAX_ID_LENGTH
+                        && service.service_type.len() <= ServiceEndpoint::MAX_TYPE_LENGTH
+                        && service.endpoint.len() <= ServiceEndpoint::MAX_ENDPOINT_LENGTH,
+                    AxiomAgentError::EntryTooLong
+                );
+                require!(
+                    !did_document.services.iter().any(|s| s.id == service.id),
+                    AxiomAgentError::DuplicateEntry
+                );
+                require!(
+                    did_document.services.len() < DidDocument::MAX_SERVICES,
+                    AxiomAgentError::DidDocumentFull
+                );
+                did_document.services.push(service);
+            }
+            DidDocumentUpdate::RemoveService(id) => {
+                let index = did_document.services.iter().position(|s| s.id == id)
+                    .ok_or(AxiomAgentError::EntryNotFound)?;
+                did_document.services.remove(index);
+            }
+        }
         
-        msg!("Cryptid DID created for agent: {}", agent_metadata.did);
-        msg!("DID Document: {}", did_document);
+        did_document.updated_at = Clock::get()?.unix_timestamp;
         
+        msg!("DID document updated for: {}", did_document.did);
         Ok(())
     }
     
     // New function to rotate keys for agent sovereignty
     pub fn rotate_agent_keys(ctx: Context<RotateAgentKeys>, new_controller: Pubkey) -> Result<()> {
-        let agent_metadata = &mut ctx.accounts.agent_metadata;
+        let did_document = &mut ctx.accounts.did_document;
+        let current_controller = ctx.accounts.authority.key();
+        
+        require!(!did_document.is_controller(&new_controller), AxiomAgentError::DuplicateEntry);
         
-        // In a full implementation, this would:
-        // 1. Update the Cryptid DID controller
-        // 2. Maintain the same DID while changing the controlling key
-        // 3. Preserve all associated reputation and credentials
+        // The DID itself stays the same, only the controlling key is swapped
+        let index = did_document.controllers.iter().position(|c| *c == current_controller)
+            .ok_or(AxiomAgentError::NotController)?;
+        did_document.controllers[index] = new_controller;
         
-        msg!("Agent keys rotated for DID: {}", agent_metadata.did);
+        // Verification methods bound to the rotated key follow it
+        for method in did_document.verification_methods.iter_mut() {
+            if method.key == current_controller {
+                method.key = new_controller;
+            }
+        }
+        did_document.updated_at = Clock::get()?.unix_timestamp;
+        
+        msg!("Agent keys rotated for DID: {}", did_document.did);
         msg!("New controller: {}", new_controller);
         
         Ok(())
@@ -199,14 +333,14 @@ pub mod axiom_id {
     
     // New function to verify agent identity through Cryptid DID
     pub fn verify_agent_identity(ctx: Context<VerifyAgentIdentity>) -> Result<()> {
-        let agent_metadata = &ctx.accounts.agent_metadata;
+        let did_document = &ctx.accounts.did_document;
         
-        // In a full implementation, this would:
-        // 1. Verify the agent's Cryptid DID is valid
-        // 2. Check that the DID is properly associated with the agent
-        // 3. Confirm the agent has not been revoked or compromised
+        require!(
+            did_document.is_controller(&ctx.accounts.controller.key()),
+            AxiomAgentError::NotController
+        );
         
-        msg!("Agent identity verified for DID: {}", agent_metadata.did);
+        msg!("Agent identity verified for DID: {}", did_document.did);
         
         Ok(())
     }
@@ -243,6 +377,111 @@ pub struct AgentMetadata {
     pub bump: u8,
 }
 
+// On-chain DID document of an agent, sized to the entries it currently holds
+#[account]
+pub struct DidDocument {
+    // The did:sol key of the agent
+    pub did: Pubkey,
+    
+    // The AgentMetadata account this document belongs to
+    pub agent_metadata: Pubkey,
+    
+    // Keys allowed to update the document and act for the agent
+    pub controllers: Vec<Pubkey>,
+    
+    pub verification_methods: Vec<VerificationMethod>,
+    
+    pub services: Vec<ServiceEndpoint>,
+    
+    pub updated_at: i64,
+    
+    pub bump: u8,
+}
+
+impl DidDocument {
+    pub const MAX_CONTROLLERS: usize = 5;
+    pub const MAX_VERIFICATION_METHODS: usize = 10;
+    pub const MAX_SERVICES: usize = 5;
+    
+    // Account size for the given number of entries
+    pub fn space(controllers: usize, verification_methods: usize, services: usize) -> usize {
+        8 + // discriminator
+        32 + // did
+        32 + // agent_metadata
+        (4 + controllers * 32) +
+        (4 + verification_methods * VerificationMethod::INIT_SPACE) +
+        (4 + services * ServiceEndpoint::INIT_SPACE) +
+        8 + // updated_at
+        1 // bump
+    }
+    
+    // Account size once the update is applied
+    pub fn space_after(&self, update: &DidDocumentUpdate) -> usize {
+        let mut controllers = self.controllers.len();
+        let mut verification_methods = self.verification_methods.len();
+        let mut services = self.services.len();
+        
+        match update {
+            DidDocumentUpdate::AddController(_) => controllers += 1,
+            DidDocumentUpdate::RemoveController(_) => controllers = controllers.saturating_sub(1),
+            DidDocumentUpdate::AddVerificationMethod(_) => verification_methods += 1,
+            DidDocumentUpdate::RemoveVerificationMethod(_) => verification_methods = verification_methods.saturating_sub(1),
+            DidDocumentUpdate::AddService(_) => services += 1,
+            DidDocumentUpdate::RemoveService(_) => services = services.saturating_sub(1),
+        }
+        
+        Self::space(controllers, verification_methods, services)
+    }
+    
+    pub fn is_controller(&self, key: &Pubkey) -> bool {
+        self.controllers.contains(key)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct VerificationMethod {
+    #[max_len(32)]
+    pub id: String,
+    pub key: Pubkey,
+    // Bitmask of the verification relationships this key is used for
+    pub flags: u8,
+}
+
+impl VerificationMethod {
+    pub const MAX_ID_LENGTH: usize = 32;
+    
+    pub const AUTHENTICATION: u8 = 1 << 0;
+    pub const ASSERTION_METHOD: u8 = 1 << 1;
+    pub const KEY_AGREEMENT: u8 = 1 << 2;
+    pub const CAPABILITY_INVOCATION: u8 = 1 << 3;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ServiceEndpoint {
+    #[max_len(32)]
+    pub id: String,
+    #[max_len(32)]
+    pub service_type: String,
+    #[max_len(128)]
+    pub endpoint: String,
+}
+
+impl ServiceEndpoint {
+    pub const MAX_ID_LENGTH: usize = 32;
+    pub const MAX_TYPE_LENGTH: usize = 32;
+    pub const MAX_ENDPOINT_LENGTH: usize = 128;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum DidDocumentUpdate {
+    AddController(Pubkey),
+    RemoveController(Pubkey),
+    AddVerificationMethod(VerificationMethod),
+    RemoveVerificationMethod(String),
+    AddService(ServiceEndpoint),
+    RemoveService(String),
+}
+
 // --- 2. ADDED A HELPER CALCULATION ---
 // We define the data structure and calculate its size.
 #[account]
@@ -318,14 +557,54 @@ pub struct MintSoulToAgent<'info> {
     pub agent_metadata: Account<'info, AgentMetadata>,
     
     /// CHECK: This is the Cryptid PDA that controls the soul
+    #[account(
+        constraint = agent_pda.key() == agent_metadata.agent_pda
+    )]
     pub agent_pda: AccountInfo<'info>,
     
-    /// CHECK: This is the soul mint that will be created
-    pub soul_mint: AccountInfo<'info>,
+    // Soul mint created by the Agent Soul Factory
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub soul_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Creator record of the soul mint, validated by the Agent Soul Factory
+    pub soul_mint_record: AccountInfo<'info>,
+    
+    /// CHECK: The agent PDA's associated token account, created by the factory
+    #[account(mut)]
+    pub agent_token_account: AccountInfo<'info>,
+    
+    /// CHECK: The factory PDA holding the soul mint authority, checked against the mint
+    #[account(
+        seeds = [b"agent-soul-factory"],
+        bump,
+        seeds::program = soul_factory_program.key()
+    )]
+    pub soul_factory_authority: AccountInfo<'info>,
+    
+    // Protocol fee accounts, validated by the factory
+    /// CHECK: Validated by the Agent Soul Factory
+    #[account(mut)]
+    pub payer_token_account: AccountInfo<'info>,
+    
+    /// CHECK: Validated by the Agent Soul Factory
+    #[account(mut)]
+    pub fee_vault: AccountInfo<'info>,
+    
+    /// CHECK: Validated by the Agent Soul Factory
+    pub axiom_token_mint: AccountInfo<'info>,
+    
+    /// CHECK: Validated by the Agent Soul Factory
+    pub fee_config: AccountInfo<'info>,
     
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    pub soul_factory_program: Program<'info, AgentSoulFactory>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
@@ -429,58 +708,97 @@ pub struct SlashTokens<'info> {
     pub system_program: Program<'info, System>,
 }
 
-// Define the context for updating reputation
+// Define the context for syncing reputation from the attestation ledger
 #[derive(Accounts)]
-pub struct UpdateReputation<'info> {
+pub struct SyncReputation<'info> {
     #[account(
         mut,
-        seeds = [b"axiom-identity", authority.key().as_ref()],
+        seeds = [b"axiom-identity", identity_account.authority.as_ref()],
         bump
     )]
     pub identity_account: Account<'info, AxiomAiIdentity>,
     
-    // Authority must be the same as the identity owner
     #[account(
-        constraint = authority.key() == identity_account.authority
+        seeds = [b"reputation", identity_account.authority.as_ref()],
+        bump = reputation.bump,
+        seeds::program = axiom_attestations::ID
     )]
-    pub authority: Signer<'info>,
+    pub reputation: Account<'info, Reputation>,
+    
+    #[account(
+        seeds = [b"attestation-config"],
+        bump = attestation_config.bump,
+        seeds::program = axiom_attestations::ID
+    )]
+    pub attestation_config: Account<'info, AttestationConfig>,
 }
 
 // New accounts struct for creating Cryptid DID
 #[derive(Accounts)]
 pub struct CreateCryptidDID<'info> {
     #[account(
-        mut,
         seeds = [b"agent-metadata", agent_metadata.did.as_ref()],
         bump = agent_metadata.bump
     )]
     pub agent_metadata: Account<'info, AgentMetadata>,
     
-    #[account(mut)]
+    #[account(
+        init,
+        payer = authority,
+        space = DidDocument::space(1, 1, 0),
+        seeds = [b"did-document", agent_metadata.key().as_ref()],
+        bump
+    )]
+    pub did_document: Account<'info, DidDocument>,
+    
+    // The DID key itself becomes the first controller
+    #[account(
+        mut,
+        constraint = authority.key() == agent_metadata.did @ AxiomAgentError::NotController
+    )]
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
-// New accounts struct for rotating agent keys
+// New accounts struct for updating a DID document
 #[derive(Accounts)]
-pub struct RotateAgentKeys<'info> {
+#[instruction(update: DidDocumentUpdate)]
+pub struct UpdateDidDocument<'info> {
     #[account(
         mut,
-        seeds = [b"agent-metadata", agent_metadata.did.as_ref()],
-        bump = agent_metadata.bump
+        seeds = [b"did-document", did_document.agent_metadata.as_ref()],
+        bump = did_document.bump,
+        realloc = did_document.space_after(&update),
+        realloc::payer = authority,
+        realloc::zero = false
     )]
-    pub agent_metadata: Account<'info, AgentMetadata>,
+    pub did_document: Account<'info, DidDocument>,
     
-    // Authority must be the current controller
+    // Authority must be a current controller
     #[account(
-        constraint = authority.key() == agent_metadata.did
+        mut,
+        constraint = did_document.is_controller(&authority.key()) @ AxiomAgentError::NotController
     )]
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
+// New accounts struct for rotating agent keys
+#[derive(Accounts)]
+pub struct RotateAgentKeys<'info> {
+    #[account(
+        mut,
+        seeds = [b"did-document", did_document.agent_metadata.as_ref()],
+        bump = did_document.bump
+    )]
+    pub did_document: Account<'info, DidDocument>,
+    
+    // Authority must be the current controller
+    pub authority: Signer<'info>,
+}
+
 // New accounts struct for verifying agent identity
 #[derive(Accounts)]
 pub struct VerifyAgentIdentity<'info> {
@@ -490,8 +808,14 @@ pub struct VerifyAgentIdentity<'info> {
     )]
     pub agent_metadata: Account<'info, AgentMetadata>,
     
-    /// CHECK: This account can be any valid pubkey
-    pub verifier: AccountInfo<'info>,
+    #[account(
+        seeds = [b"did-document", agent_metadata.key().as_ref()],
+        bump = did_document.bump
+    )]
+    pub did_document: Account<'info, DidDocument>,
+    
+    // The key claiming to act for the agent
+    pub controller: Signer<'info>,
 }
 
 // New accounts struct for linking existing Cryptid DID
@@ -521,4 +845,28 @@ pub enum AxiomAgentError {
     
     #[msg("Arithmetic overflow")]
     Overflow,
+    
+    #[msg("Mint does not match a soul token (supply, decimals or extensions)")]
+    InvalidSoulMint,
+    
+    #[msg("Soul token account is not the agent's associated token account")]
+    InvalidSoulTokenAccount,
+    
+    #[msg("Signer is not a controller of this DID")]
+    NotController,
+    
+    #[msg("DID document has reached its maximum number of entries")]
+    DidDocumentFull,
+    
+    #[msg("DID document entry already exists")]
+    DuplicateEntry,
+    
+    #[msg("DID document entry not found")]
+    EntryNotFound,
+    
+    #[msg("DID document entry exceeds the maximum length")]
+    EntryTooLong,
+    
+    #[msg("Cannot remove the last controller of a DID")]
+    LastController,
 }
\ No newline at end of file
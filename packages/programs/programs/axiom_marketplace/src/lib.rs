@@ -1,7 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
+};
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS"); // Replace with actual ID after deploy
 
+const SECONDS_PER_DAY: i64 = 86400;
+
 #[program]
 pub mod axiom_marketplace {
     use super::*;
@@ -19,6 +25,8 @@ pub mod axiom_marketplace {
         listing.max_rental_days = max_rental_days;
         listing.is_available = true;
         listing.total_earnings = 0;
+        listing.payment_mint = ctx.accounts.payment_mint.key();
+        listing.bump = *ctx.bumps.get("listing").unwrap();
         Ok(())
     }
 
@@ -26,40 +34,153 @@ pub mod axiom_marketplace {
         ctx: Context<RentAgent>,
         rental_days: u32,
     ) -> Result<()> {
-        let listing = &mut ctx.accounts.listing;
+        let listing = &ctx.accounts.listing;
         require!(listing.is_available, MarketplaceError::AgentNotAvailable);
+        require!(rental_days > 0, MarketplaceError::InvalidRentalDuration);
         require!(rental_days <= listing.max_rental_days, MarketplaceError::RentalTooLong);
 
-        // In a real implementation, we would transfer funds here
-        // from renter to an escrow or directly to owner
-        
+        let rental_cost = listing.price_per_day.checked_mul(rental_days as u64)
+            .ok_or(MarketplaceError::Overflow)?;
+
+        // Lock the full rental payment in the listing escrow
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.renter_token_account.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.renter.to_account_info(),
+            mint: ctx.accounts.payment_mint.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        transfer_checked(cpi_ctx, rental_cost, ctx.accounts.payment_mint.decimals)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let listing = &mut ctx.accounts.listing;
         listing.is_available = false;
         listing.renter = ctx.accounts.renter.key();
-        listing.rental_end = Clock::get()?.unix_timestamp + (rental_days as i64 * 86400);
-        
+        listing.rental_start = now;
+        listing.rental_end = now + (rental_days as i64 * SECONDS_PER_DAY);
+        listing.rental_days = rental_days;
+        listing.escrowed_amount = rental_cost;
+        listing.released_amount = 0;
+
+        msg!("Agent {} rented for {} days, {} tokens escrowed", listing.agent_id, rental_days, rental_cost);
         Ok(())
     }
 
     pub fn release_agent(ctx: Context<ReleaseAgent>) -> Result<()> {
+        let listing = &ctx.accounts.listing;
+        require!(!listing.is_available, MarketplaceError::NotRented);
+
+        // The renter can end early; once the rental is over anyone can settle it
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            listing.renter == ctx.accounts.caller.key() || now >= listing.rental_end,
+            MarketplaceError::NotRenter
+        );
+
+        // Every started day is owed to the owner, the rest goes back to the renter
+        let days_used = listing.days_started(now);
+        let owed = listing.earned_for_days(days_used)?
+            .checked_sub(listing.released_amount)
+            .ok_or(MarketplaceError::Overflow)?;
+        let refund = listing.escrowed_amount
+            .checked_sub(listing.released_amount)
+            .and_then(|remaining| remaining.checked_sub(owed))
+            .ok_or(MarketplaceError::Overflow)?;
+
+        let owner_key = listing.owner;
+        let seeds = &[
+            b"listing",
+            owner_key.as_ref(),
+            listing.agent_id.as_bytes(),
+            &[listing.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        if owed > 0 {
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.listing.to_account_info(),
+                mint: ctx.accounts.payment_mint.to_account_info(),
+            };
+
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+            transfer_checked(cpi_ctx, owed, ctx.accounts.payment_mint.decimals)?;
+        }
+
+        if refund > 0 {
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.renter_token_account.to_account_info(),
+                authority: ctx.accounts.listing.to_account_info(),
+                mint: ctx.accounts.payment_mint.to_account_info(),
+            };
+
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+            transfer_checked(cpi_ctx, refund, ctx.accounts.payment_mint.decimals)?;
+        }
+
         let listing = &mut ctx.accounts.listing;
-        require!(listing.renter == ctx.accounts.renter.key(), MarketplaceError::NotRenter);
-        
-        // Check if rental period is over or if returning early
-        // For simplicity, we just make it available again
+        listing.total_earnings = listing.total_earnings.checked_add(owed)
+            .ok_or(MarketplaceError::Overflow)?;
         listing.is_available = true;
         listing.renter = Pubkey::default();
+        listing.rental_start = 0;
         listing.rental_end = 0;
-        
+        listing.rental_days = 0;
+        listing.escrowed_amount = 0;
+        listing.released_amount = 0;
+
+        msg!("Agent released: {} paid to owner, {} refunded to renter", owed, refund);
         Ok(())
     }
 
     pub fn withdraw_earnings(ctx: Context<WithdrawEarnings>) -> Result<()> {
-        let listing = &mut ctx.accounts.listing;
+        let listing = &ctx.accounts.listing;
         require!(listing.owner == ctx.accounts.owner.key(), MarketplaceError::NotOwner);
-        
-        // Transfer accumulated earnings to owner
-        listing.total_earnings = 0;
-        
+
+        // Only fully elapsed days are released while the rental is running
+        let now = Clock::get()?.unix_timestamp;
+        let releasable = listing.earned_for_days(listing.days_elapsed(now))?
+            .checked_sub(listing.released_amount)
+            .ok_or(MarketplaceError::Overflow)?;
+        require!(releasable > 0, MarketplaceError::NothingToWithdraw);
+
+        let owner_key = listing.owner;
+        let seeds = &[
+            b"listing",
+            owner_key.as_ref(),
+            listing.agent_id.as_bytes(),
+            &[listing.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: ctx.accounts.listing.to_account_info(),
+            mint: ctx.accounts.payment_mint.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        transfer_checked(cpi_ctx, releasable, ctx.accounts.payment_mint.decimals)?;
+
+        let listing = &mut ctx.accounts.listing;
+        listing.released_amount = listing.released_amount.checked_add(releasable)
+            .ok_or(MarketplaceError::Overflow)?;
+        listing.total_earnings = listing.total_earnings.checked_add(releasable)
+            .ok_or(MarketplaceError::Overflow)?;
+
+        msg!("Withdrawn {} tokens of rental earnings", releasable);
         Ok(())
     }
 }
@@ -73,46 +194,149 @@ pub struct Listing {
     pub is_available: bool,
     pub renter: Pubkey,
     pub rental_end: i64,
-    pub total_earnings: u64,
+    pub total_earnings: u64,   // Lifetime earnings paid out to the owner
+    pub payment_mint: Pubkey,  // Token-2022 mint rentals are paid in
+    pub rental_start: i64,
+    pub rental_days: u32,
+    pub escrowed_amount: u64,  // Payment locked for the current rental
+    pub released_amount: u64,  // Part of the escrow already paid to the owner
+    pub bump: u8,
+}
+
+impl Listing {
+    pub const LEN: usize = 8 + 32 + 50 + 8 + 4 + 1 + 32 + 8 + 8 + 32 + 8 + 4 + 8 + 8 + 1;
+
+    // Whole days of the current rental that have fully elapsed
+    fn days_elapsed(&self, now: i64) -> u32 {
+        let elapsed = now.saturating_sub(self.rental_start).max(0) / SECONDS_PER_DAY;
+        (elapsed.min(self.rental_days as i64)) as u32
+    }
+
+    // Days of the current rental that have at least started
+    fn days_started(&self, now: i64) -> u32 {
+        let elapsed = now.saturating_sub(self.rental_start).max(0);
+        let started = (elapsed + SECONDS_PER_DAY - 1) / SECONDS_PER_DAY;
+        (started.min(self.rental_days as i64)) as u32
+    }
+
+    fn earned_for_days(&self, days: u32) -> Result<u64> {
+        self.price_per_day.checked_mul(days as u64)
+            .ok_or(error!(MarketplaceError::Overflow))
+    }
 }
 
 #[derive(Accounts)]
 #[instruction(agent_id: String)]
 pub struct ListAgent<'info> {
     #[account(
-        init, 
-        payer = owner, 
-        space = 8 + 32 + 50 + 8 + 4 + 1 + 32 + 8 + 8,
+        init,
+        payer = owner,
+        space = Listing::LEN,
         seeds = [b"listing", owner.key().as_ref(), agent_id.as_bytes()],
         bump
     )]
     pub listing: Account<'info, Listing>,
+    #[account(
+        init,
+        payer = owner,
+        token::mint = payment_mint,
+        token::authority = listing,
+        token::token_program = token_program,
+        seeds = [b"escrow", listing.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub payment_mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 pub struct RentAgent<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"listing", listing.owner.as_ref(), listing.agent_id.as_bytes()],
+        bump = listing.bump,
+        has_one = payment_mint
+    )]
     pub listing: Account<'info, Listing>,
+    #[account(
+        mut,
+        seeds = [b"escrow", listing.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = renter,
+    )]
+    pub renter_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub payment_mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub renter: Signer<'info>,
+    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 pub struct ReleaseAgent<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"listing", listing.owner.as_ref(), listing.agent_id.as_bytes()],
+        bump = listing.bump,
+        has_one = payment_mint
+    )]
     pub listing: Account<'info, Listing>,
-    pub renter: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"escrow", listing.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = listing.owner,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = listing.renter,
+    )]
+    pub renter_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    pub caller: Signer<'info>,  // The renter, or anyone once the rental has ended
+    pub token_program: Program<'info, Token2022>,
 }
 
 #[derive(Accounts)]
 pub struct WithdrawEarnings<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"listing", listing.owner.as_ref(), listing.agent_id.as_bytes()],
+        bump = listing.bump,
+        has_one = payment_mint
+    )]
     pub listing: Account<'info, Listing>,
+    #[account(
+        mut,
+        seeds = [b"escrow", listing.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub payment_mint: InterfaceAccount<'info, Mint>,
     pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token2022>,
 }
 
 #[error_code]
@@ -121,8 +345,16 @@ pub enum MarketplaceError {
     AgentNotAvailable,
     #[msg("Rental duration exceeds maximum allowed")]
     RentalTooLong,
-    #[msg("Caller is not the current renter")]
+    #[msg("Caller is not the current renter and the rental has not ended")]
     NotRenter,
     #[msg("Caller is not the owner")]
     NotOwner,
+    #[msg("Rental duration must be at least one day")]
+    InvalidRentalDuration,
+    #[msg("No rental earnings are available to withdraw")]
+    NothingToWithdraw,
+    #[msg("Agent is not currently rented")]
+    NotRented,
+    #[msg("Arithmetic overflow")]
+    Overflow,
 }
@@ -1,7 +1,32 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
 
 declare_id!("AXiomPoHWProg111111111111111111111111111111");
 
+// Maximum number of authorized verifiers on the schema
+pub const MAX_VERIFIERS: usize = 10;
+
+// Domain prefix of the message a verifier signs for an ed25519-verified write
+pub const WORK_MESSAGE_PREFIX: &[u8] = b"axiom-pohw-work";
+
+// Maximum number of schema versions that can be active at once
+pub const MAX_SCHEMA_VERSIONS: usize = 8;
+
+// Layout of HumanWorkAttestation written by this program version. Layout 1 predates
+// the layout_version field; later layouts store it first so migrations can dispatch on it.
+pub const ATTESTATION_LAYOUT_VERSION: u8 = 2;
+
+// Quality scores are expressed in basis points
+pub const MAX_QUALITY_SCORE: u16 = 10_000;
+
+// Number of recent tasks the rolling quality score averages over
+pub const QUALITY_WINDOW: u64 = 20;
+
 #[program]
 pub mod axiom_pohw {
     use super::*;
@@ -10,20 +35,99 @@ pub mod axiom_pohw {
         let schema = &mut ctx.accounts.schema;
         schema.version = 1;
         schema.authority = ctx.accounts.authority.key();
+        schema.active_versions = vec![1];
+        schema.verifiers = Vec::new();
+        schema.decay_half_life = 0;
         schema.bump = *ctx.bumps.get("schema").unwrap();
         
         msg!("PoHW Schema initialized");
         Ok(())
     }
 
+    // Register a new schema version and make it the current one
+    pub fn register_schema_version(ctx: Context<ManageSchema>, version: u8) -> Result<()> {
+        let schema = &mut ctx.accounts.schema;
+        require!(version > schema.version, PohwError::InvalidSchemaVersion);
+        require!(
+            schema.active_versions.len() < MAX_SCHEMA_VERSIONS,
+            PohwError::TooManySchemaVersions
+        );
+        schema.active_versions.push(version);
+        schema.version = version;
+        
+        msg!("PoHW schema version {} registered", version);
+        Ok(())
+    }
+
+    // Stop accepting writes for an older schema version
+    pub fn deactivate_schema_version(ctx: Context<ManageSchema>, version: u8) -> Result<()> {
+        let schema = &mut ctx.accounts.schema;
+        require!(version != schema.version, PohwError::CannotDeactivateCurrentVersion);
+        let index = schema.active_versions.iter().position(|v| *v == version)
+            .ok_or(PohwError::InactiveSchemaVersion)?;
+        schema.active_versions.remove(index);
+        
+        msg!("PoHW schema version {} deactivated", version);
+        Ok(())
+    }
+
+    // Set how quickly inactive attestations decay; 0 disables decay
+    pub fn set_decay_half_life(ctx: Context<ManageSchema>, half_life: i64) -> Result<()> {
+        require!(half_life >= 0, PohwError::InvalidHalfLife);
+        
+        let schema = &mut ctx.accounts.schema;
+        schema.decay_half_life = half_life;
+        
+        msg!("PoHW decay half-life set to {}s", half_life);
+        Ok(())
+    }
+
+    // Authorize a verifier to write PoHW attestations
+    pub fn add_verifier(ctx: Context<ManageSchema>, verifier: Pubkey) -> Result<()> {
+        let schema = &mut ctx.accounts.schema;
+        require!(!schema.verifiers.contains(&verifier), PohwError::VerifierAlreadyAdded);
+        require!(schema.verifiers.len() < MAX_VERIFIERS, PohwError::TooManyVerifiers);
+        schema.verifiers.push(verifier);
+        
+        msg!("Verifier added: {}", verifier);
+        Ok(())
+    }
+
+    pub fn remove_verifier(ctx: Context<ManageSchema>, verifier: Pubkey) -> Result<()> {
+        let schema = &mut ctx.accounts.schema;
+        let index = schema.verifiers.iter().position(|v| *v == verifier)
+            .ok_or(PohwError::UnknownVerifier)?;
+        schema.verifiers.remove(index);
+        
+        msg!("Verifier removed: {}", verifier);
+        Ok(())
+    }
+
     pub fn record_human_work(ctx: Context<RecordHumanWork>, data: WorkData) -> Result<()> {
+        verify_work_signature(
+            &ctx.accounts.schema,
+            &ctx.accounts.verifier,
+            ctx.accounts.instructions.as_ref(),
+            &ctx.accounts.user.key(),
+            &data,
+        )?;
+        require!(
+            ctx.accounts.schema.is_active(data.schema_version),
+            PohwError::InactiveSchemaVersion
+        );
+        require!(data.quality_score <= MAX_QUALITY_SCORE, PohwError::InvalidQualityScore);
+        
         let attestation = &mut ctx.accounts.attestation;
+        attestation.layout_version = ATTESTATION_LAYOUT_VERSION;
         attestation.axiom_id_holder = ctx.accounts.user.key();
         attestation.schema_version = data.schema_version;
         attestation.total_tasks = data.total_tasks;
         attestation.quality_score = data.quality_score;
         attestation.last_active_ts = data.last_active_ts;
         attestation.specialization_tier = data.specialization_tier;
+        attestation.decayed_at = data.last_active_ts;
+        attestation.verifier = ctx.accounts.verifier.key();
+        attestation.verified_at = Clock::get()?.unix_timestamp;
         attestation.bump = *ctx.bumps.get("attestation").unwrap();
         
         msg!("Human work recorded for user: {}", attestation.axiom_id_holder);
@@ -31,16 +135,194 @@ pub mod axiom_pohw {
     }
 
     pub fn update_human_work(ctx: Context<UpdateHumanWork>, data: WorkData) -> Result<()> {
+        verify_work_signature(
+            &ctx.accounts.schema,
+            &ctx.accounts.verifier,
+            ctx.accounts.instructions.as_ref(),
+            &ctx.accounts.user.key(),
+            &data,
+        )?;
+        require!(
+            ctx.accounts.schema.is_active(data.schema_version),
+            PohwError::InactiveSchemaVersion
+        );
+        require!(data.quality_score <= MAX_QUALITY_SCORE, PohwError::InvalidQualityScore);
+        
         let attestation = &mut ctx.accounts.attestation;
+        // Activity only moves forward, so an older signed update cannot be replayed
+        require!(data.last_active_ts > attestation.last_active_ts, PohwError::StaleWorkData);
         attestation.schema_version = data.schema_version;
         attestation.total_tasks = data.total_tasks;
         attestation.quality_score = data.quality_score;
         attestation.last_active_ts = data.last_active_ts;
         attestation.specialization_tier = data.specialization_tier;
+        attestation.decayed_at = data.last_active_ts;
+        attestation.verifier = ctx.accounts.verifier.key();
+        attestation.verified_at = Clock::get()?.unix_timestamp;
         
         msg!("Human work updated for user: {}", attestation.axiom_id_holder);
         Ok(())
     }
+
+    // Count one completed task towards the user's attestation. Called through CPI by
+    // work sources such as axiom_train_earn, whose signing PDA must be an authorized verifier.
+    pub fn record_task_completion(
+        ctx: Context<RecordTaskCompletion>,
+        quality_score: u16,
+        completed_at: i64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.schema.verifiers.contains(&ctx.accounts.verifier.key()),
+            PohwError::UnknownVerifier
+        );
+        require!(quality_score <= MAX_QUALITY_SCORE, PohwError::InvalidQualityScore);
+        
+        let attestation = &mut ctx.accounts.attestation;
+        if attestation.axiom_id_holder == Pubkey::default() {
+            attestation.layout_version = ATTESTATION_LAYOUT_VERSION;
+            attestation.axiom_id_holder = ctx.accounts.user.key();
+            attestation.bump = *ctx.bumps.get("attestation").unwrap();
+        }
+        // Completions are always counted under the current schema version
+        attestation.schema_version = ctx.accounts.schema.version;
+        
+        // Settle any inactivity decay before folding in the new task
+        let now = Clock::get()?.unix_timestamp;
+        attestation.apply_decay(now, ctx.accounts.schema.decay_half_life);
+        
+        attestation.quality_score = attestation.rolled_quality_score(quality_score);
+        attestation.total_tasks = attestation.total_tasks.checked_add(1)
+            .ok_or(PohwError::Overflow)?;
+        attestation.last_active_ts = attestation.last_active_ts.max(completed_at);
+        attestation.decayed_at = attestation.decayed_at.max(attestation.last_active_ts);
+        attestation.verifier = ctx.accounts.verifier.key();
+        attestation.verified_at = now;
+        
+        msg!(
+            "Task completion recorded for user: {}, total tasks: {}, quality: {}",
+            attestation.axiom_id_holder,
+            attestation.total_tasks,
+            attestation.quality_score
+        );
+        Ok(())
+    }
+
+    // Read-only view of the quality score and tier after inactivity decay, returned via return data
+    pub fn get_effective_work(ctx: Context<GetEffectiveWork>) -> Result<EffectiveWork> {
+        let effective = ctx.accounts.attestation.effective_work(
+            Clock::get()?.unix_timestamp,
+            ctx.accounts.schema.decay_half_life,
+        );
+        
+        msg!(
+            "Effective work for {}: quality {}, tier {}",
+            ctx.accounts.attestation.axiom_id_holder,
+            effective.quality_score,
+            effective.specialization_tier
+        );
+        Ok(effective)
+    }
+
+    // Permissionless crank that writes accrued decay back to the attestation,
+    // demoting the specialization tier by one level per elapsed half-life
+    pub fn apply_decay(ctx: Context<ApplyDecay>) -> Result<()> {
+        let attestation = &mut ctx.accounts.attestation;
+        let applied = attestation.apply_decay(
+            Clock::get()?.unix_timestamp,
+            ctx.accounts.schema.decay_half_life,
+        );
+        require!(applied, PohwError::NothingToDecay);
+        
+        msg!(
+            "Decay applied for {}: quality {}, tier {}",
+            attestation.axiom_id_holder,
+            attestation.quality_score,
+            attestation.specialization_tier
+        );
+        Ok(())
+    }
+
+    // Upgrade the schema account from an older layout, filling new fields with the
+    // defaults initialize_schema uses. Must run before attestations can be migrated.
+    // Permissionless; the payer covers any extra rent.
+    pub fn migrate_schema(ctx: Context<MigrateSchema>) -> Result<()> {
+        let schema_info = ctx.accounts.schema.to_account_info();
+        require_keys_eq!(*schema_info.owner, crate::ID, PohwError::UnsupportedLayout);
+        
+        let migrated = {
+            let data = schema_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == Schema::DISCRIMINATOR,
+                PohwError::UnsupportedLayout
+            );
+            Schema::from_legacy_layout(&data[8..])?
+        };
+        
+        // Top up rent for the larger layout, then grow the account
+        let new_len = 8 + Schema::INIT_SPACE;
+        let rent_due = Rent::get()?.minimum_balance(new_len)
+            .saturating_sub(schema_info.lamports());
+        if rent_due > 0 {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: schema_info.clone(),
+                },
+            );
+            transfer(cpi_ctx, rent_due)?;
+        }
+        if schema_info.data_len() < new_len {
+            schema_info.realloc(new_len, true)?;
+        }
+        
+        let mut data = schema_info.try_borrow_mut_data()?;
+        migrated.try_serialize(&mut &mut data[..])?;
+        
+        msg!("PoHW schema migrated at version {}", migrated.version);
+        Ok(())
+    }
+
+    // Upgrade an attestation written with an older account layout to the current one,
+    // growing the account as needed. Permissionless; the payer covers any extra rent.
+    pub fn migrate_attestation(ctx: Context<MigrateAttestation>) -> Result<()> {
+        let attestation_info = ctx.accounts.attestation.to_account_info();
+        require_keys_eq!(*attestation_info.owner, crate::ID, PohwError::UnsupportedLayout);
+        
+        let migrated = {
+            let data = attestation_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == HumanWorkAttestation::DISCRIMINATOR,
+                PohwError::UnsupportedLayout
+            );
+            HumanWorkAttestation::from_legacy_layout(&data[8..])?
+        };
+        require_keys_eq!(migrated.axiom_id_holder, ctx.accounts.user.key(), PohwError::UnsupportedLayout);
+        
+        // Top up rent for the larger layout, then grow the account
+        let new_len = 8 + HumanWorkAttestation::INIT_SPACE;
+        let rent_due = Rent::get()?.minimum_balance(new_len)
+            .saturating_sub(attestation_info.lamports());
+        if rent_due > 0 {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: attestation_info.clone(),
+                },
+            );
+            transfer(cpi_ctx, rent_due)?;
+        }
+        if attestation_info.data_len() < new_len {
+            attestation_info.realloc(new_len, true)?;
+        }
+        
+        let mut data = attestation_info.try_borrow_mut_data()?;
+        migrated.try_serialize(&mut &mut data[..])?;
+        
+        msg!("PoHW attestation migrated to layout {} for user: {}", ATTESTATION_LAYOUT_VERSION, migrated.axiom_id_holder);
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -60,6 +342,19 @@ pub struct InitializeSchema<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct ManageSchema<'info> {
+    #[account(
+        mut,
+        seeds = [b"pohw-schema"],
+        bump = schema.bump,
+        has_one = authority @ PohwError::Unauthorized
+    )]
+    pub schema: Account<'info, Schema>,
+    
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 #[instruction(data: WorkData)]
 pub struct RecordHumanWork<'info> {
@@ -90,6 +385,13 @@ pub struct RecordHumanWork<'info> {
     )]
     pub attestation: Account<'info, HumanWorkAttestation>,
 
+    /// CHECK: Authorized verifier, either signing this transaction or an ed25519 instruction
+    pub verifier: AccountInfo<'info>,
+
+    /// CHECK: Instructions sysvar, needed when the verifier signs through ed25519
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: Option<AccountInfo<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -121,29 +423,298 @@ pub struct UpdateHumanWork<'info> {
     )]
     pub attestation: Account<'info, HumanWorkAttestation>,
 
+    /// CHECK: Authorized verifier, either signing this transaction or an ed25519 instruction
+    pub verifier: AccountInfo<'info>,
+
+    /// CHECK: Instructions sysvar, needed when the verifier signs through ed25519
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: Option<AccountInfo<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordTaskCompletion<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// User (holder of the attestation)
+    pub user: SystemAccount<'info>,
+
+    /// Schema account
+    #[account(
+        seeds = [b"pohw-schema"],
+        bump = schema.bump
+    )]
+    pub schema: Account<'info, Schema>,
+
+    /// Attestation account (PDA), created on the user's first completion
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + HumanWorkAttestation::INIT_SPACE,
+        seeds = [
+            b"pohw-attestation",
+            schema.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub attestation: Account<'info, HumanWorkAttestation>,
+
+    /// Authorized verifier, usually a work source program's PDA
+    pub verifier: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetEffectiveWork<'info> {
+    #[account(
+        seeds = [b"pohw-schema"],
+        bump = schema.bump
+    )]
+    pub schema: Account<'info, Schema>,
+
+    #[account(
+        seeds = [
+            b"pohw-attestation",
+            schema.key().as_ref(),
+            attestation.axiom_id_holder.as_ref()
+        ],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, HumanWorkAttestation>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyDecay<'info> {
+    #[account(
+        seeds = [b"pohw-schema"],
+        bump = schema.bump
+    )]
+    pub schema: Account<'info, Schema>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pohw-attestation",
+            schema.key().as_ref(),
+            attestation.axiom_id_holder.as_ref()
+        ],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, HumanWorkAttestation>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateSchema<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Schema in an older layout, decoded by hand in the handler
+    #[account(
+        mut,
+        seeds = [b"pohw-schema"],
+        bump
+    )]
+    pub schema: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAttestation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// User (holder of the attestation)
+    pub user: SystemAccount<'info>,
+
+    /// Schema account
+    #[account(
+        seeds = [b"pohw-schema"],
+        bump = schema.bump
+    )]
+    pub schema: Account<'info, Schema>,
+
+    /// CHECK: Attestation in an older layout, decoded by hand in the handler
+    #[account(
+        mut,
+        seeds = [
+            b"pohw-attestation",
+            schema.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub attestation: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct Schema {
-    pub version: u8,
+    pub version: u8,                // Current schema version
     pub authority: Pubkey,
+    #[max_len(8)]
+    pub active_versions: Vec<u8>,   // Versions writes may still use
+    #[max_len(10)]
+    pub verifiers: Vec<Pubkey>,     // Keys allowed to write attestations
+    pub decay_half_life: i64,       // Seconds of inactivity that halve quality and drop a tier
     pub bump: u8,
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct HumanWorkAttestation {
+    pub layout_version: u8,         // Account layout, see ATTESTATION_LAYOUT_VERSION
     pub axiom_id_holder: Pubkey,
     pub schema_version: u8,
     pub total_tasks: u64,
     pub quality_score: u16,
     pub last_active_ts: i64,
     pub specialization_tier: u8,
+    pub decayed_at: i64,            // Point up to which decay is reflected in the stored data
+    pub verifier: Pubkey,           // Verifier that wrote the current data
+    pub verified_at: i64,           // When the current data was written
     pub bump: u8,
 }
 
+// Quality score and tier after inactivity decay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EffectiveWork {
+    pub quality_score: u16,
+    pub specialization_tier: u8,
+}
+
+impl Schema {
+    pub fn is_active(&self, version: u8) -> bool {
+        self.active_versions.contains(&version)
+    }
+
+    // Decode account data (without discriminator) stored in the original layout. Schema
+    // layouts carry no version, but each was allocated at its own fixed size.
+    pub fn from_legacy_layout(data: &[u8]) -> Result<Self> {
+        match data.len() {
+            SchemaV1::LEN => {
+                let v1 = SchemaV1::deserialize(&mut &data[..])?;
+                Ok(Self {
+                    version: v1.version,
+                    authority: v1.authority,
+                    active_versions: vec![v1.version],
+                    verifiers: Vec::new(),
+                    decay_half_life: 0,
+                    bump: v1.bump,
+                })
+            }
+            Self::INIT_SPACE => err!(PohwError::AlreadyMigrated),
+            _ => err!(PohwError::UnsupportedLayout),
+        }
+    }
+}
+
+// Original schema layout, before verifiers
+#[derive(AnchorDeserialize)]
+pub struct SchemaV1 {
+    pub version: u8,
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+impl SchemaV1 {
+    pub const LEN: usize = 1 + 32 + 1;
+}
+
+// Original attestation layout, before verifiers and layout versions
+#[derive(AnchorDeserialize)]
+pub struct HumanWorkAttestationV1 {
+    pub axiom_id_holder: Pubkey,
+    pub schema_version: u8,
+    pub total_tasks: u64,
+    pub quality_score: u16,
+    pub last_active_ts: i64,
+    pub specialization_tier: u8,
+    pub bump: u8,
+}
+
+impl HumanWorkAttestationV1 {
+    pub const LEN: usize = 32 + 1 + 8 + 2 + 8 + 1 + 1;
+}
+
+impl HumanWorkAttestation {
+    // Decode account data (without discriminator) stored in the original layout
+    pub fn from_legacy_layout(data: &[u8]) -> Result<Self> {
+        if data.len() == HumanWorkAttestationV1::LEN {
+            let v1 = HumanWorkAttestationV1::deserialize(&mut &data[..])?;
+            return Ok(Self {
+                layout_version: ATTESTATION_LAYOUT_VERSION,
+                axiom_id_holder: v1.axiom_id_holder,
+                schema_version: v1.schema_version,
+                total_tasks: v1.total_tasks,
+                quality_score: v1.quality_score,
+                last_active_ts: v1.last_active_ts,
+                specialization_tier: v1.specialization_tier,
+                decayed_at: v1.last_active_ts,
+                verifier: Pubkey::default(),
+                verified_at: 0,
+                bump: v1.bump,
+            });
+        }
+        
+        match data.first() {
+            Some(&ATTESTATION_LAYOUT_VERSION) => err!(PohwError::AlreadyMigrated),
+            _ => err!(PohwError::UnsupportedLayout),
+        }
+    }
+
+    // Quality and tier at `now`. Each full half-life since `decayed_at` halves the
+    // quality score and drops one tier; a partial period decays quality linearly.
+    pub fn effective_work(&self, now: i64, half_life: i64) -> EffectiveWork {
+        let elapsed = now.saturating_sub(self.decayed_at);
+        if half_life <= 0 || elapsed <= 0 {
+            return EffectiveWork {
+                quality_score: self.quality_score,
+                specialization_tier: self.specialization_tier,
+            };
+        }
+        let halvings = elapsed / half_life;
+        let quality = if halvings >= 16 { 0 } else { self.quality_score >> halvings };
+        let remainder = elapsed % half_life;
+        let decay = (quality as i64) * remainder / (2 * half_life);
+        EffectiveWork {
+            quality_score: quality - decay as u16,
+            specialization_tier: self.specialization_tier
+                .saturating_sub(halvings.min(u8::MAX as i64) as u8),
+        }
+    }
+
+    // Write back the decay of every full half-life elapsed since `decayed_at`. The
+    // partial period is left for later, so effective_work is unchanged by applying it.
+    // Returns whether anything was applied.
+    pub fn apply_decay(&mut self, now: i64, half_life: i64) -> bool {
+        let elapsed = now.saturating_sub(self.decayed_at);
+        if half_life <= 0 || elapsed < half_life {
+            return false;
+        }
+        let halvings = elapsed / half_life;
+        self.quality_score = if halvings >= 16 { 0 } else { self.quality_score >> halvings };
+        self.specialization_tier = self.specialization_tier
+            .saturating_sub(halvings.min(u8::MAX as i64) as u8);
+        self.decayed_at += halvings * half_life;
+        true
+    }
+
+    // Running average over the first QUALITY_WINDOW tasks, then an exponential
+    // moving average with the same window so recent work dominates
+    pub fn rolled_quality_score(&self, quality_score: u16) -> u16 {
+        let window = self.total_tasks.saturating_add(1).min(QUALITY_WINDOW);
+        let rolled = (self.quality_score as u64 * (window - 1) + quality_score as u64) / window;
+        rolled as u16
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct WorkData {
     pub schema_version: u8,
@@ -151,4 +722,127 @@ pub struct WorkData {
     pub quality_score: u16,
     pub last_active_ts: i64,
     pub specialization_tier: u8,
-}
\ No newline at end of file
+}
+
+impl WorkData {
+    // Message an authorized verifier signs to approve this data for a user
+    pub fn message(&self, schema: &Pubkey, user: &Pubkey) -> Result<Vec<u8>> {
+        let mut message = Vec::with_capacity(WORK_MESSAGE_PREFIX.len() + 64 + 20);
+        message.extend_from_slice(WORK_MESSAGE_PREFIX);
+        message.extend_from_slice(schema.as_ref());
+        message.extend_from_slice(user.as_ref());
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+// The verifier must be on the schema's list and either sign the transaction or
+// sign the work message in an ed25519 instruction placed right before this one
+fn verify_work_signature(
+    schema: &Account<Schema>,
+    verifier: &AccountInfo,
+    instructions: Option<&AccountInfo>,
+    user: &Pubkey,
+    data: &WorkData,
+) -> Result<()> {
+    require!(schema.verifiers.contains(verifier.key), PohwError::UnknownVerifier);
+    if verifier.is_signer {
+        return Ok(());
+    }
+    
+    let instructions = instructions.ok_or(PohwError::MissingVerifierSignature)?;
+    let current_index = load_current_index_checked(instructions)?;
+    require!(current_index > 0, PohwError::MissingVerifierSignature);
+    let ed25519_ix = load_instruction_at_checked((current_index - 1) as usize, instructions)?;
+    require_keys_eq!(ed25519_ix.program_id, ed25519_program::ID, PohwError::MissingVerifierSignature);
+    
+    let message = data.message(&schema.key(), user)?;
+    let (signer, signed_message) = parse_ed25519_instruction(&ed25519_ix.data)
+        .ok_or(PohwError::InvalidVerifierSignature)?;
+    require!(
+        signer == verifier.key.as_ref() && signed_message == message.as_slice(),
+        PohwError::InvalidVerifierSignature
+    );
+    Ok(())
+}
+
+// Extract the public key and message from a single-signature ed25519 instruction
+// whose data is all held inline. The ed25519 program has already checked the signature.
+fn parse_ed25519_instruction(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    const HEADER_LEN: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    const INLINE: u16 = u16::MAX;
+    
+    if data.len() < HEADER_LEN + OFFSETS_LEN || data[0] != 1 {
+        return None;
+    }
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = HEADER_LEN;
+    let signature_ix = read_u16(offsets + 2);
+    let public_key_offset = read_u16(offsets + 4) as usize;
+    let public_key_ix = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_size = read_u16(offsets + 10) as usize;
+    let message_ix = read_u16(offsets + 12);
+    if signature_ix != INLINE || public_key_ix != INLINE || message_ix != INLINE {
+        return None;
+    }
+    
+    let public_key = data.get(public_key_offset..public_key_offset + 32)?;
+    let message = data.get(message_offset..message_offset + message_size)?;
+    Some((public_key, message))
+}
+
+#[error_code]
+pub enum PohwError {
+    #[msg("Unauthorized")]
+    Unauthorized,
+    
+    #[msg("Verifier is already authorized")]
+    VerifierAlreadyAdded,
+    
+    #[msg("Too many verifiers")]
+    TooManyVerifiers,
+    
+    #[msg("Verifier is not authorized")]
+    UnknownVerifier,
+    
+    #[msg("Missing verifier signature")]
+    MissingVerifierSignature,
+    
+    #[msg("Verifier signature does not match the work data")]
+    InvalidVerifierSignature,
+    
+    #[msg("Work data is older than the recorded activity")]
+    StaleWorkData,
+    
+    #[msg("Quality score must be at most 10000")]
+    InvalidQualityScore,
+    
+    #[msg("Schema version must be newer than the current one")]
+    InvalidSchemaVersion,
+    
+    #[msg("Too many active schema versions")]
+    TooManySchemaVersions,
+    
+    #[msg("Schema version is not active")]
+    InactiveSchemaVersion,
+    
+    #[msg("The current schema version cannot be deactivated")]
+    CannotDeactivateCurrentVersion,
+    
+    #[msg("Account already uses the current layout")]
+    AlreadyMigrated,
+    
+    #[msg("Unsupported account layout")]
+    UnsupportedLayout,
+    
+    #[msg("Half-life must not be negative")]
+    InvalidHalfLife,
+    
+    #[msg("No decay has accrued since the last update")]
+    NothingToDecay,
+    
+    #[msg("Arithmetic overflow")]
+    Overflow,
+}
@@ -3,10 +3,38 @@ use anchor_spl::{
     token_2022::Token2022,
     token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked, Burn, burn},
 };
+use axiom_attestations::{AttestationConsumer, Sentiment};
+use axiom_staking::{program::AxiomStaking, StakingPool, UnbondingTicket, UserStake};
 
 // This is our new Program ID. Anchor will update this for us later.
 declare_id!("9sKxhfHdQgjWBuoztEYonKepba2zGcN2QtWowCmAfWzD");
 
+// Maximum length of a slash reason
+pub const MAX_REASON_LEN: usize = 200;
+
+// Maximum number of evidence accounts attached to a slash proposal
+pub const MAX_EVIDENCE: usize = 8;
+
+// Maximum number of arbiters that can resolve appeals
+pub const MAX_ARBITERS: usize = 5;
+
+// Default window during which an accused agent can appeal (3 days)
+pub const DEFAULT_CHALLENGE_PERIOD: i64 = 259_200;
+
+// Default time arbiters or governance have to resolve an appeal (7 days)
+pub const DEFAULT_APPEAL_PERIOD: i64 = 604_800;
+
+// Maximum number of offense categories in the slashing policy
+pub const MAX_OFFENSE_CATEGORIES: usize = 8;
+
+// Maximum number of offenses remembered per agent for repeat escalation
+pub const MAX_RECENT_OFFENSES: usize = 16;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// Maximum number of attestation schemas trusted as evidence for automatic slashing
+pub const MAX_NEGATIVE_SCHEMAS: usize = 4;
+
 #[program]
 pub mod axiom_slashing {
     use super::*;
@@ -16,109 +44,430 @@ pub mod axiom_slashing {
         let slashing_config = &mut ctx.accounts.slashing_config;
         slashing_config.authority = authority;
         slashing_config.total_slashes = 0;
+        slashing_config.challenge_period = DEFAULT_CHALLENGE_PERIOD;
+        slashing_config.appeal_period = DEFAULT_APPEAL_PERIOD;
+        slashing_config.appeal_bond = 0;
+        slashing_config.arbiters = Vec::new();
+        slashing_config.arbiter_threshold = 0;
+        slashing_config.governance = Pubkey::default();
+        // Until splits are configured everything goes to the penalty pool
+        slashing_config.whistleblower_bps = 0;
+        slashing_config.burn_bps = 0;
+        slashing_config.staker_reward_bps = 0;
+        slashing_config.negative_schemas = Vec::new();
+        slashing_config.penalty_pool = Pubkey::default();
         slashing_config.bump = *ctx.bumps.get("slashing_config").unwrap();
         
         msg!("Slashing configuration initialized");
         Ok(())
     }
 
-    // Slash tokens from a malicious agent
+    // Configure the appeal process: challenge window, counter-bond and who resolves appeals
+    pub fn set_dispute_config(
+        ctx: Context<UpdateSlashingConfig>,
+        challenge_period: i64,
+        appeal_period: i64,
+        appeal_bond: u64,
+        arbiters: Vec<Pubkey>,
+        arbiter_threshold: u8,
+        governance: Pubkey,
+    ) -> Result<()> {
+        require!(challenge_period > 0 && appeal_period > 0, SlashingError::InvalidDisputeConfig);
+        require!(arbiters.len() <= MAX_ARBITERS, SlashingError::InvalidDisputeConfig);
+        require!(
+            (arbiter_threshold as usize) <= arbiters.len(),
+            SlashingError::InvalidDisputeConfig
+        );
+        // A zero threshold would let the first arbiter vote decide alone
+        require!(
+            arbiters.is_empty() || arbiter_threshold > 0,
+            SlashingError::InvalidDisputeConfig
+        );
+        // Appeals need at least one way to be resolved
+        require!(
+            arbiter_threshold > 0 || governance != Pubkey::default(),
+            SlashingError::InvalidDisputeConfig
+        );
+        for (i, arbiter) in arbiters.iter().enumerate() {
+            require!(!arbiters[..i].contains(arbiter), SlashingError::InvalidDisputeConfig);
+        }
+
+        let slashing_config = &mut ctx.accounts.slashing_config;
+        slashing_config.challenge_period = challenge_period;
+        slashing_config.appeal_period = appeal_period;
+        slashing_config.appeal_bond = appeal_bond;
+        slashing_config.arbiters = arbiters;
+        slashing_config.arbiter_threshold = arbiter_threshold;
+        slashing_config.governance = governance;
+
+        msg!("Dispute config updated: challenge period {}s, appeal bond {}", challenge_period, appeal_bond);
+        Ok(())
+    }
+
+    // Set how seized stake is split; whatever is left over goes to the penalty pool
+    pub fn set_slash_split(
+        ctx: Context<UpdateSlashingConfig>,
+        whistleblower_bps: u16,
+        burn_bps: u16,
+        staker_reward_bps: u16,
+    ) -> Result<()> {
+        let total = whistleblower_bps as u64 + burn_bps as u64 + staker_reward_bps as u64;
+        require!(total <= BPS_DENOMINATOR, SlashingError::InvalidSlashSplit);
+
+        let slashing_config = &mut ctx.accounts.slashing_config;
+        slashing_config.whistleblower_bps = whistleblower_bps;
+        slashing_config.burn_bps = burn_bps;
+        slashing_config.staker_reward_bps = staker_reward_bps;
+
+        msg!(
+            "Slash split updated: {} bps whistleblower, {} bps burn, {} bps stakers",
+            whistleblower_bps, burn_bps, staker_reward_bps
+        );
+        Ok(())
+    }
+
+    // Set the token account that receives the penalty pool share and forfeited appeal bonds.
+    // Slashes cannot be executed until this is set.
+    pub fn set_penalty_pool(ctx: Context<SetPenaltyPool>) -> Result<()> {
+        let slashing_config = &mut ctx.accounts.slashing_config;
+        slashing_config.penalty_pool = ctx.accounts.penalty_pool.key();
+
+        msg!("Penalty pool set to {}", slashing_config.penalty_pool);
+        Ok(())
+    }
+
+    // Set which attestation schemas count as negative evidence for automatic slashing
+    pub fn set_negative_schemas(ctx: Context<UpdateSlashingConfig>, negative_schemas: Vec<Pubkey>) -> Result<()> {
+        require!(negative_schemas.len() <= MAX_NEGATIVE_SCHEMAS, SlashingError::TooManySchemas);
+
+        let slashing_config = &mut ctx.accounts.slashing_config;
+        slashing_config.negative_schemas = negative_schemas;
+
+        msg!("Trusted negative schemas updated");
+        Ok(())
+    }
+
+    // Open a slash proposal against an agent; no tokens move until it is finalized
     pub fn slash_agent(
         ctx: Context<SlashAgent>,
         amount: u64,
         reason: String,
+        evidence: Vec<Pubkey>,
+        reporter: Pubkey,
     ) -> Result<()> {
         // Verify authority
         require!(
             ctx.accounts.authority.key() == ctx.accounts.slashing_config.authority,
             SlashingError::Unauthorized
         );
+        require!(amount > 0, SlashingError::InsufficientFunds);
+        require!(reason.len() <= MAX_REASON_LEN, SlashingError::ReasonTooLong);
+        require!(evidence.len() <= MAX_EVIDENCE, SlashingError::TooMuchEvidence);
 
-        // Transfer tokens from user to pool as penalty
-        let cpi_accounts = TransferChecked {
-            from: ctx.accounts.user_token_account.to_account_info(),
-            to: ctx.accounts.penalty_pool.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
-            mint: ctx.accounts.staked_token_mint.to_account_info(),
-        };
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        
-        transfer_checked(cpi_ctx, amount, ctx.accounts.staked_token_mint.decimals)?;
+        let now = Clock::get()?.unix_timestamp;
+        let challenge_ends_at = now.checked_add(ctx.accounts.slashing_config.challenge_period)
+            .ok_or(SlashingError::Overflow)?;
+
+        let slash_proposal = &mut ctx.accounts.slash_proposal;
+        slash_proposal.agent = ctx.accounts.user.key();
+        slash_proposal.staking_pool = ctx.accounts.staking_pool.key();
+        slash_proposal.proposer = ctx.accounts.authority.key();
+        slash_proposal.reporter = reporter;
+        slash_proposal.amount = amount;
+        slash_proposal.reason = reason;
+        slash_proposal.evidence = evidence;
+        slash_proposal.created_at = now;
+        slash_proposal.challenge_ends_at = challenge_ends_at;
+        slash_proposal.appeal_ends_at = 0;
+        slash_proposal.status = SlashStatus::Pending;
+        slash_proposal.appeal_bond = 0;
+        slash_proposal.arbiter_votes = Vec::new();
+        slash_proposal.uphold_votes = 0;
+        slash_proposal.dismiss_votes = 0;
+        slash_proposal.bump = *ctx.bumps.get("slash_proposal").unwrap();
+
+        // Unbonded tokens stay slashable until the proposal is settled
+        hold_withdrawals(
+            &ctx.accounts.slashing_config,
+            &ctx.accounts.staking_pool,
+            &ctx.accounts.user_stake,
+            &ctx.accounts.user,
+            &ctx.accounts.staking_program,
+            true,
+        )?;
 
         // Update slashing config
         let slashing_config = &mut ctx.accounts.slashing_config;
         slashing_config.total_slashes = slashing_config.total_slashes.checked_add(1)
             .ok_or(SlashingError::Overflow)?;
 
-        // Record the slash event
-        let slash_record = &mut ctx.accounts.slash_record;
-        slash_record.user = ctx.accounts.user.key();
-        slash_record.amount = amount;
-        slash_record.reason = reason;
-        slash_record.timestamp = Clock::get()?.unix_timestamp;
-        slash_record.bump = *ctx.bumps.get("slash_record").unwrap();
+        msg!("Slash proposal opened for {} tokens, challenge window ends at {}", amount, challenge_ends_at);
+        Ok(())
+    }
+
+    // The accused agent posts the counter-bond to appeal a pending slash
+    pub fn appeal_slash(ctx: Context<AppealSlash>) -> Result<()> {
+        let slash_proposal = &mut ctx.accounts.slash_proposal;
+        require!(slash_proposal.status == SlashStatus::Pending, SlashingError::InvalidSlashStatus);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < slash_proposal.challenge_ends_at, SlashingError::ChallengeWindowClosed);
+        let appeal_ends_at = now.checked_add(ctx.accounts.slashing_config.appeal_period)
+            .ok_or(SlashingError::Overflow)?;
 
-        // Update user stake account to reflect the slash
-        let user_stake = &mut ctx.accounts.user_stake;
-        user_stake.amount = user_stake.amount.checked_sub(amount)
-            .ok_or(SlashingError::InsufficientFunds)?;
+        let bond = ctx.accounts.slashing_config.appeal_bond;
+        if bond > 0 {
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.agent_token_account.to_account_info(),
+                to: ctx.accounts.appeal_bond_vault.to_account_info(),
+                authority: ctx.accounts.agent.to_account_info(),
+                mint: ctx.accounts.staked_token_mint.to_account_info(),
+            };
+            
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            
+            transfer_checked(cpi_ctx, bond, ctx.accounts.staked_token_mint.decimals)?;
+        }
 
-        msg!("Slashed {} tokens from user for reason: {}", amount, slash_record.reason);
+        slash_proposal.status = SlashStatus::Appealed;
+        slash_proposal.appeal_bond = bond;
+        slash_proposal.appeal_ends_at = appeal_ends_at;
+
+        msg!("Slash appealed with a bond of {}, must be resolved by {}", bond, appeal_ends_at);
         Ok(())
     }
 
-    // Burn tokens as a more severe penalty
-    pub fn burn_tokens(
-        ctx: Context<BurnTokens>,
-        amount: u64,
-        reason: String,
-    ) -> Result<()> {
-        // Verify authority
+    // Resolve an appealed slash, either by arbiter vote or directly by governance
+    pub fn resolve_slash(ctx: Context<ResolveSlash>, uphold: bool) -> Result<()> {
+        let slashing_config = &ctx.accounts.slashing_config;
+        let slash_proposal = &mut ctx.accounts.slash_proposal;
+        let resolver = ctx.accounts.resolver.key();
+        require!(slash_proposal.status == SlashStatus::Appealed, SlashingError::InvalidSlashStatus);
         require!(
-            ctx.accounts.authority.key() == ctx.accounts.slashing_config.authority,
-            SlashingError::Unauthorized
+            Clock::get()?.unix_timestamp < slash_proposal.appeal_ends_at,
+            SlashingError::AppealWindowClosed
         );
 
-        // Burn tokens directly
-        let cpi_accounts = Burn {
-            mint: ctx.accounts.staked_token_mint.to_account_info(),
-            from: ctx.accounts.user_token_account.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
-        };
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        
-        burn(cpi_ctx, amount)?;
+        if slashing_config.governance != Pubkey::default() && resolver == slashing_config.governance {
+            slash_proposal.status = if uphold { SlashStatus::Upheld } else { SlashStatus::Dismissed };
+        } else {
+            require!(slashing_config.arbiters.contains(&resolver), SlashingError::NotArbiter);
+            require!(!slash_proposal.arbiter_votes.contains(&resolver), SlashingError::AlreadyVoted);
+            slash_proposal.arbiter_votes.push(resolver);
 
-        // Update slashing config
-        let slashing_config = &mut ctx.accounts.slashing_config;
-        slashing_config.total_slashes = slashing_config.total_slashes.checked_add(1)
+            let threshold = slashing_config.arbiter_threshold;
+            if uphold {
+                slash_proposal.uphold_votes += 1;
+                if slash_proposal.uphold_votes >= threshold {
+                    slash_proposal.status = SlashStatus::Upheld;
+                }
+            } else {
+                slash_proposal.dismiss_votes += 1;
+                if slash_proposal.dismiss_votes >= threshold {
+                    slash_proposal.status = SlashStatus::Dismissed;
+                }
+            }
+        }
+
+        msg!("Slash resolution vote recorded, status: {:?}", slash_proposal.status);
+        Ok(())
+    }
+
+    // An appeal that was not resolved in time, e.g. because the arbiters split, is
+    // dismissed so the agent's bond can be returned. Anyone can call this.
+    pub fn expire_appeal(ctx: Context<ExpireAppeal>) -> Result<()> {
+        let slash_proposal = &mut ctx.accounts.slash_proposal;
+        require!(slash_proposal.status == SlashStatus::Appealed, SlashingError::InvalidSlashStatus);
+        require!(
+            Clock::get()?.unix_timestamp >= slash_proposal.appeal_ends_at,
+            SlashingError::AppealWindowOpen
+        );
+
+        slash_proposal.status = SlashStatus::Dismissed;
+
+        msg!("Appeal expired unresolved, slash dismissed");
+        Ok(())
+    }
+
+    // Seize the stake once a proposal is upheld or its challenge window passed unappealed.
+    // Anyone can call this once the outcome is final. The agent's unbonding tickets are
+    // passed in remaining_accounts; if the stake and tickets cannot cover the proposed
+    // amount, every open ticket must be passed and the slash takes what is there.
+    pub fn execute_slash<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteSlash<'info>>) -> Result<()> {
+        let slash_proposal = &ctx.accounts.slash_proposal;
+        match slash_proposal.status {
+            SlashStatus::Pending => require!(
+                Clock::get()?.unix_timestamp >= slash_proposal.challenge_ends_at,
+                SlashingError::ChallengeWindowOpen
+            ),
+            SlashStatus::Upheld => {}
+            _ => return err!(SlashingError::InvalidSlashStatus),
+        }
+        let appeal_bond = slash_proposal.appeal_bond;
+
+        let tickets = load_unbonding_tickets(
+            ctx.remaining_accounts,
+            &ctx.accounts.staking_pool.key(),
+            &ctx.accounts.user.key(),
+        )?;
+        let available = tickets.iter()
+            .try_fold(ctx.accounts.user_stake.amount, |total, (_, amount)| total.checked_add(*amount))
             .ok_or(SlashingError::Overflow)?;
+        if available < slash_proposal.amount {
+            require!(
+                tickets.len() as u64 == ctx.accounts.user_stake.open_unbonding_tickets,
+                SlashingError::MissingUnbondingTickets
+            );
+        }
+        let amount = slash_proposal.amount.min(available);
 
-        // Record the burn event
-        let slash_record = &mut ctx.accounts.slash_record;
-        slash_record.user = ctx.accounts.user.key();
-        slash_record.amount = amount;
-        slash_record.reason = reason;
-        slash_record.timestamp = Clock::get()?.unix_timestamp;
-        slash_record.bump = *ctx.bumps.get("slash_record").unwrap();
+        if amount > 0 {
+            seize_stake(
+                SlashSource {
+                    slashing_config: &ctx.accounts.slashing_config,
+                    staking_pool: &ctx.accounts.staking_pool,
+                    user_stake: &ctx.accounts.user_stake,
+                    user: &ctx.accounts.user,
+                    pool_token_account: &ctx.accounts.pool_token_account,
+                    slash_vault: &ctx.accounts.slash_vault,
+                    staked_token_mint: &ctx.accounts.staked_token_mint,
+                    staking_program: &ctx.accounts.staking_program,
+                    token_program: &ctx.accounts.token_program,
+                },
+                &tickets,
+                amount,
+            )?;
+        }
+
+        distribute_slash(
+            SlashPayout {
+                slashing_config: &ctx.accounts.slashing_config,
+                slash_vault: &ctx.accounts.slash_vault,
+                whistleblowers: vec![(ctx.accounts.whistleblower_token_account.to_account_info(), 1)],
+                penalty_pool: &ctx.accounts.penalty_pool,
+                staking_pool: &ctx.accounts.staking_pool,
+                reward_token_account: &ctx.accounts.reward_token_account,
+                staked_token_mint: &ctx.accounts.staked_token_mint,
+                staking_program: &ctx.accounts.staking_program,
+                token_program: &ctx.accounts.token_program,
+            },
+            amount,
+        )?;
+        hold_withdrawals(
+            &ctx.accounts.slashing_config,
+            &ctx.accounts.staking_pool,
+            &ctx.accounts.user_stake,
+            &ctx.accounts.user,
+            &ctx.accounts.staking_program,
+            false,
+        )?;
 
-        // Update user stake account to reflect the burn
-        let user_stake = &mut ctx.accounts.user_stake;
-        user_stake.amount = user_stake.amount.checked_sub(amount)
-            .ok_or(SlashingError::InsufficientFunds)?;
+        // A failed appeal forfeits the counter-bond to the penalty pool
+        if appeal_bond > 0 {
+            let bond_vault = ctx.accounts.appeal_bond_vault.as_ref()
+                .ok_or(SlashingError::MissingAppealBondVault)?;
+            release_appeal_bond(
+                &ctx.accounts.slashing_config,
+                bond_vault,
+                ctx.accounts.penalty_pool.to_account_info(),
+                &ctx.accounts.staked_token_mint,
+                ctx.accounts.token_program.to_account_info(),
+                appeal_bond,
+            )?;
+        }
 
-        msg!("Burned {} tokens from user for reason: {}", amount, slash_record.reason);
+        let slash_proposal = &mut ctx.accounts.slash_proposal;
+        slash_proposal.status = SlashStatus::Executed;
+
+        msg!("Slashed {} tokens from user for reason: {}", amount, slash_proposal.reason);
         Ok(())
     }
 
-    // Automatically slash based on negative SAS attestations
-    pub fn auto_slash_for_negative_attestations(
-        ctx: Context<AutoSlashForNegativeAttestations>,
-        negative_attestations: u64,
+    // Return the counter-bond to the agent after a successful appeal and lift the
+    // hold on its withdrawals. Anyone can call this once the slash is dismissed.
+    pub fn dismiss_slash(ctx: Context<DismissSlash>) -> Result<()> {
+        let slash_proposal = &ctx.accounts.slash_proposal;
+        require!(slash_proposal.status == SlashStatus::Dismissed, SlashingError::InvalidSlashStatus);
+        let appeal_bond = slash_proposal.appeal_bond;
+
+        if appeal_bond > 0 {
+            release_appeal_bond(
+                &ctx.accounts.slashing_config,
+                &ctx.accounts.appeal_bond_vault,
+                ctx.accounts.agent_token_account.to_account_info(),
+                &ctx.accounts.staked_token_mint,
+                ctx.accounts.token_program.to_account_info(),
+                appeal_bond,
+            )?;
+        }
+
+        hold_withdrawals(
+            &ctx.accounts.slashing_config,
+            &ctx.accounts.staking_pool,
+            &ctx.accounts.user_stake,
+            &ctx.accounts.user,
+            &ctx.accounts.staking_program,
+            false,
+        )?;
+
+        let slash_proposal = &mut ctx.accounts.slash_proposal;
+        slash_proposal.appeal_bond = 0;
+        slash_proposal.status = SlashStatus::Released;
+
+        msg!("Slash dismissed, returned appeal bond of {}", appeal_bond);
+        Ok(())
+    }
+
+    // Create or replace the policy used by automatic slashing
+    pub fn set_slashing_policy(
+        ctx: Context<SetSlashingPolicy>,
+        categories: Vec<OffensePolicy>,
+        repeat_multiplier_bps: u16,
+        repeat_window: i64,
+        max_penalty_bps: u16,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.slashing_config.authority,
+            SlashingError::Unauthorized
+        );
+        require!(
+            !categories.is_empty() && categories.len() <= MAX_OFFENSE_CATEGORIES,
+            SlashingError::InvalidSlashingPolicy
+        );
+        for offense in categories.iter() {
+            require!(
+                offense.penalty_bps as u64 <= BPS_DENOMINATOR && offense.jail_period >= 0,
+                SlashingError::InvalidSlashingPolicy
+            );
+        }
+        // Repeat offenses may only escalate the penalty
+        require!(repeat_multiplier_bps as u64 >= BPS_DENOMINATOR, SlashingError::InvalidSlashingPolicy);
+        require!(repeat_window > 0, SlashingError::InvalidSlashingPolicy);
+        require!(max_penalty_bps as u64 <= BPS_DENOMINATOR, SlashingError::InvalidSlashingPolicy);
+
+        let slashing_policy = &mut ctx.accounts.slashing_policy;
+        slashing_policy.categories = categories;
+        slashing_policy.repeat_multiplier_bps = repeat_multiplier_bps;
+        slashing_policy.repeat_window = repeat_window;
+        slashing_policy.max_penalty_bps = max_penalty_bps;
+        slashing_policy.bump = *ctx.bumps.get("slashing_policy").unwrap();
+
+        msg!("Slashing policy updated with {} offense categories", slashing_policy.categories.len());
+        Ok(())
+    }
+
+    // Automatically slash based on negative SAS attestations, escalating with the
+    // agent's recent offense history according to the slashing policy. Attestations
+    // are passed as (attestation, marker) pairs in remaining_accounts and each one
+    // can only ever be used as evidence once. Attestations count by their weight.
+    // The attesters' token accounts and then the agent's open unbonding tickets follow
+    // the attestations in remaining_accounts. The whistleblower share is split between
+    // the attesters by weight, and stake that is still unbonding cannot escape the slash.
+    pub fn auto_slash_for_negative_attestations<'info>(
+        ctx: Context<'_, '_, '_, 'info, AutoSlashForNegativeAttestations<'info>>,
+        category: u8,
+        attester_accounts: u8,
+        unbonding_tickets: u8,
     ) -> Result<()> {
         // Verify authority
         require!(
@@ -126,23 +475,134 @@ pub mod axiom_slashing {
             SlashingError::Unauthorized
         );
 
-        // Calculate slash amount based on negative attestations
-        // Each negative attestation results in a 1% slash of staked tokens, capped at 50%
-        let slash_percentage = (negative_attestations as f64 * 1.0).min(50.0);
-        let slash_amount = ((ctx.accounts.user_stake.amount as f64) * (slash_percentage / 100.0)) as u64;
+        let evidence_len = ctx.remaining_accounts.len()
+            .checked_sub(attester_accounts as usize + unbonding_tickets as usize)
+            .ok_or(SlashingError::InvalidRemainingAccounts)?;
+        let (evidence_accounts, payout_accounts) = ctx.remaining_accounts.split_at(evidence_len);
+        let (attester_token_accounts, ticket_accounts) = payout_accounts.split_at(attester_accounts as usize);
 
-        // Transfer tokens from user to penalty pool
-        let cpi_accounts = TransferChecked {
-            from: ctx.accounts.user_token_account.to_account_info(),
-            to: ctx.accounts.penalty_pool.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
-            mint: ctx.accounts.staked_token_mint.to_account_info(),
-        };
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        
-        transfer_checked(cpi_ctx, slash_amount, ctx.accounts.staked_token_mint.decimals)?;
+        let tickets = load_unbonding_tickets(
+            ticket_accounts,
+            &ctx.accounts.staking_pool.key(),
+            &ctx.accounts.user.key(),
+        )?;
+
+        let consumed = AttestationConsumer {
+            program_id: ctx.program_id,
+            scope: ctx.accounts.slashing_config.key(),
+            subject: ctx.accounts.user.key(),
+            trusted_schemas: &ctx.accounts.slashing_config.negative_schemas,
+            sentiment: Some(Sentiment::Negative),
+            payer: ctx.accounts.authority.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        }.consume(evidence_accounts)?;
+        let negative_attestations = consumed.iter()
+            .map(|attestation| attestation.weight as u64)
+            .sum::<u64>();
+        require!(negative_attestations > 0, SlashingError::NoValidAttestations);
+
+        // Each attester whose evidence was counted is paid through their own token account
+        let mut whistleblowers: Vec<(AccountInfo<'info>, u64)> = Vec::new();
+        let mut attester_weights: Vec<(Pubkey, u64)> = Vec::new();
+        for attestation in consumed.iter() {
+            match attester_weights.iter_mut().find(|(attester, _)| *attester == attestation.attester) {
+                Some((_, weight)) => *weight += attestation.weight as u64,
+                None => attester_weights.push((attestation.attester, attestation.weight as u64)),
+            }
+        }
+        for (attester, weight) in attester_weights {
+            let token_account_info = attester_token_accounts.iter()
+                .find(|info| {
+                    InterfaceAccount::<TokenAccount>::try_from(info).is_ok_and(|token_account| {
+                        token_account.owner == attester
+                            && token_account.mint == ctx.accounts.staked_token_mint.key()
+                    })
+                })
+                .ok_or(SlashingError::MissingAttesterTokenAccount)?;
+            whistleblowers.push((token_account_info.clone(), weight));
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let slashing_policy = &ctx.accounts.slashing_policy;
+        let offense = slashing_policy.categories.get(category as usize)
+            .ok_or(SlashingError::InvalidOffenseCategory)?;
+
+        // Only offenses inside the rolling window count towards escalation
+        let offense_history = &mut ctx.accounts.offense_history;
+        if offense_history.agent == Pubkey::default() {
+            offense_history.agent = ctx.accounts.user.key();
+            offense_history.bump = *ctx.bumps.get("offense_history").unwrap();
+        }
+        offense_history.prune(now, slashing_policy.repeat_window);
+        let prior_offenses = offense_history.recent_offenses.len() as u32;
+
+        let penalty_bps = slashing_policy.penalty_bps(offense, negative_attestations, prior_offenses)?;
+        let slashable = tickets.iter()
+            .try_fold(ctx.accounts.user_stake.amount, |total, (_, amount)| total.checked_add(*amount))
+            .ok_or(SlashingError::Overflow)?;
+        let slash_amount = (slashable as u128)
+            .checked_mul(penalty_bps as u128)
+            .ok_or(SlashingError::Overflow)?
+            / BPS_DENOMINATOR as u128;
+        let slash_amount = u64::try_from(slash_amount).map_err(|_| SlashingError::Overflow)?;
+        require!(slash_amount > 0, SlashingError::InsufficientFunds);
+
+        offense_history.record(category, now)?;
+
+        seize_stake(
+            SlashSource {
+                slashing_config: &ctx.accounts.slashing_config,
+                staking_pool: &ctx.accounts.staking_pool,
+                user_stake: &ctx.accounts.user_stake,
+                user: &ctx.accounts.user,
+                pool_token_account: &ctx.accounts.pool_token_account,
+                slash_vault: &ctx.accounts.slash_vault,
+                staked_token_mint: &ctx.accounts.staked_token_mint,
+                staking_program: &ctx.accounts.staking_program,
+                token_program: &ctx.accounts.token_program,
+            },
+            &tickets,
+            slash_amount,
+        )?;
+
+        distribute_slash(
+            SlashPayout {
+                slashing_config: &ctx.accounts.slashing_config,
+                slash_vault: &ctx.accounts.slash_vault,
+                whistleblowers,
+                penalty_pool: &ctx.accounts.penalty_pool,
+                staking_pool: &ctx.accounts.staking_pool,
+                reward_token_account: &ctx.accounts.reward_token_account,
+                staked_token_mint: &ctx.accounts.staked_token_mint,
+                staking_program: &ctx.accounts.staking_program,
+                token_program: &ctx.accounts.token_program,
+            },
+            slash_amount,
+        )?;
+
+        // Jail the agent in the staking program for the category's jail period
+        if offense.jail_period > 0 {
+            let jailed_until = now.checked_add(offense.jail_period)
+                .ok_or(SlashingError::Overflow)?;
+            let offense_history = &mut ctx.accounts.offense_history;
+            offense_history.jailed_until = offense_history.jailed_until.max(jailed_until);
+
+            let cpi_accounts = axiom_staking::cpi::accounts::Jail {
+                pool: ctx.accounts.staking_pool.to_account_info(),
+                user_stake: ctx.accounts.user_stake.to_account_info(),
+                staker: ctx.accounts.user.to_account_info(),
+                slash_authority: ctx.accounts.slashing_config.to_account_info(),
+            };
+            
+            let bump = ctx.accounts.slashing_config.bump;
+            let seeds = &[b"slashing-config".as_ref(), &[bump]];
+            let signer = &[&seeds[..]];
+            
+            let cpi_program = ctx.accounts.staking_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            
+            axiom_staking::cpi::jail(cpi_ctx, jailed_until)?;
+        }
 
         // Update slashing config
         let slashing_config = &mut ctx.accounts.slashing_config;
@@ -153,21 +613,234 @@ pub mod axiom_slashing {
         let slash_record = &mut ctx.accounts.slash_record;
         slash_record.user = ctx.accounts.user.key();
         slash_record.amount = slash_amount;
-        slash_record.reason = format!("Auto-slash for {} negative attestations", negative_attestations);
-        slash_record.timestamp = Clock::get()?.unix_timestamp;
+        slash_record.reason = format!(
+            "Auto-slash for {} negative attestations (category {}, {} prior offenses)",
+            negative_attestations, category, prior_offenses
+        );
+        slash_record.timestamp = now;
         slash_record.bump = *ctx.bumps.get("slash_record").unwrap();
 
-        // Update user stake account to reflect the slash
-        let user_stake = &mut ctx.accounts.user_stake;
-        user_stake.amount = user_stake.amount.checked_sub(slash_amount)
-            .ok_or(SlashingError::InsufficientFunds)?;
-
-        msg!("Auto-slashed {} tokens ({}%) from user for {} negative attestations", 
-             slash_amount, slash_percentage, negative_attestations);
+        msg!("Auto-slashed {} tokens ({} bps) from user for {} negative attestations", 
+             slash_amount, penalty_bps, negative_attestations);
         Ok(())
     }
 }
 
+// Accounts the seized stake is taken from
+pub struct SlashSource<'a, 'info> {
+    pub slashing_config: &'a Account<'info, SlashingConfig>,
+    pub staking_pool: &'a Account<'info, StakingPool>,
+    pub user_stake: &'a Account<'info, UserStake>,
+    pub user: &'a AccountInfo<'info>,
+    pub pool_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub slash_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub staked_token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub staking_program: &'a Program<'info, AxiomStaking>,
+    pub token_program: &'a Program<'info, Token2022>,
+}
+
+// Validate the agent's unbonding tickets and return each with its amount
+fn load_unbonding_tickets<'a, 'info>(
+    ticket_accounts: &'a [AccountInfo<'info>],
+    staking_pool: &Pubkey,
+    user: &Pubkey,
+) -> Result<Vec<(&'a AccountInfo<'info>, u64)>> {
+    let mut tickets = Vec::with_capacity(ticket_accounts.len());
+    for (i, ticket_info) in ticket_accounts.iter().enumerate() {
+        require!(
+            !ticket_accounts[..i].iter().any(|other| other.key == ticket_info.key),
+            SlashingError::InvalidUnbondingTicket
+        );
+        let ticket = Account::<UnbondingTicket>::try_from(ticket_info)
+            .map_err(|_| SlashingError::InvalidUnbondingTicket)?;
+        require!(
+            ticket.pool == *staking_pool && ticket.owner == *user,
+            SlashingError::InvalidUnbondingTicket
+        );
+        tickets.push((ticket_info, ticket.amount));
+    }
+    Ok(tickets)
+}
+
+// Seize stake into the slashing vault through axiom_staking::slash, signed by the
+// slashing config PDA. Takes from the active stake first and then from each
+// unbonding ticket in turn.
+fn seize_stake<'info>(
+    source: SlashSource<'_, 'info>,
+    tickets: &[(&AccountInfo<'info>, u64)],
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[b"slashing-config".as_ref(), &[source.slashing_config.bump]];
+    let signer = &[&seeds[..]];
+
+    let from_stake = amount.min(source.user_stake.amount);
+    let mut remaining = amount;
+    let sources = std::iter::once((None, from_stake))
+        .chain(tickets.iter().map(|(info, available)| (Some((*info).clone()), *available)));
+    for (unbonding_ticket, available) in sources {
+        let share = remaining.min(available);
+        if share == 0 {
+            continue;
+        }
+        let cpi_accounts = axiom_staking::cpi::accounts::Slash {
+            pool: source.staking_pool.to_account_info(),
+            user_stake: source.user_stake.to_account_info(),
+            unbonding_ticket,
+            staker: source.user.clone(),
+            pool_token_account: source.pool_token_account.to_account_info(),
+            penalty_token_account: source.slash_vault.to_account_info(),
+            staked_token_mint: source.staked_token_mint.to_account_info(),
+            slash_authority: source.slashing_config.to_account_info(),
+            token_program: source.token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(source.staking_program.to_account_info(), cpi_accounts, signer);
+        axiom_staking::cpi::slash(cpi_ctx, share)?;
+        remaining -= share;
+    }
+    require!(remaining == 0, SlashingError::InsufficientFunds);
+    Ok(())
+}
+
+// Hold the agent's unbonded withdrawals while a proposal is open, or lift the hold
+// once it is settled
+fn hold_withdrawals<'info>(
+    slashing_config: &Account<'info, SlashingConfig>,
+    staking_pool: &Account<'info, StakingPool>,
+    user_stake: &Account<'info, UserStake>,
+    user: &AccountInfo<'info>,
+    staking_program: &Program<'info, AxiomStaking>,
+    hold: bool,
+) -> Result<()> {
+    let seeds = &[b"slashing-config".as_ref(), &[slashing_config.bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = axiom_staking::cpi::accounts::HoldWithdrawals {
+        pool: staking_pool.to_account_info(),
+        user_stake: user_stake.to_account_info(),
+        staker: user.clone(),
+        slash_authority: slashing_config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(staking_program.to_account_info(), cpi_accounts, signer);
+    axiom_staking::cpi::hold_withdrawals(cpi_ctx, hold)
+}
+
+// Accounts that share the stake seized by a slash
+pub struct SlashPayout<'a, 'info> {
+    pub slashing_config: &'a Account<'info, SlashingConfig>,
+    pub slash_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub whistleblowers: Vec<(AccountInfo<'info>, u64)>, // Token accounts sharing the whistleblower cut, by weight
+    pub penalty_pool: &'a InterfaceAccount<'info, TokenAccount>,
+    pub staking_pool: &'a Account<'info, StakingPool>,
+    pub reward_token_account: &'a AccountInfo<'info>,
+    pub staked_token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub staking_program: &'a Program<'info, AxiomStaking>,
+    pub token_program: &'a Program<'info, Token2022>,
+}
+
+// Split seized stake held in the slashing vault between the whistleblower, a burn,
+// the staking pool's reward budget and the penalty pool
+fn distribute_slash<'info>(payout: SlashPayout<'_, 'info>, amount: u64) -> Result<()> {
+    let config = payout.slashing_config;
+    let seeds = &[b"slashing-config".as_ref(), &[config.bump]];
+    let signer = &[&seeds[..]];
+    let mint = payout.staked_token_mint;
+
+    let whistleblower_amount = config.share_of(amount, config.whistleblower_bps);
+    let burn_amount = config.share_of(amount, config.burn_bps);
+    let mut staker_amount = config.share_of(amount, config.staker_reward_bps);
+    // Slashed stake can only fund rewards paid in the same token
+    if payout.staking_pool.reward_token_mint != mint.key() {
+        staker_amount = 0;
+    }
+
+    // Rounding dust from the pro rata split stays with the penalty pool
+    let total_weight = payout.whistleblowers.iter().map(|(_, weight)| *weight).sum::<u64>();
+    let whistleblower_shares = payout.whistleblowers.iter()
+        .map(|(recipient, weight)| {
+            let share = if total_weight == 0 {
+                0
+            } else {
+                ((whistleblower_amount as u128) * *weight as u128 / total_weight as u128) as u64
+            };
+            (recipient.clone(), share)
+        })
+        .collect::<Vec<_>>();
+    let whistleblower_paid = whistleblower_shares.iter().map(|(_, share)| *share).sum::<u64>();
+    let penalty_amount = amount - whistleblower_paid - burn_amount - staker_amount;
+
+    let transfer_share = |to: AccountInfo<'info>, share: u64| -> Result<()> {
+        if share == 0 {
+            return Ok(());
+        }
+        let cpi_accounts = TransferChecked {
+            from: payout.slash_vault.to_account_info(),
+            to,
+            authority: config.to_account_info(),
+            mint: mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(payout.token_program.to_account_info(), cpi_accounts, signer);
+        transfer_checked(cpi_ctx, share, mint.decimals)
+    };
+    for (recipient, share) in whistleblower_shares {
+        transfer_share(recipient, share)?;
+    }
+    transfer_share(payout.penalty_pool.to_account_info(), penalty_amount)?;
+
+    if burn_amount > 0 {
+        let cpi_accounts = Burn {
+            mint: mint.to_account_info(),
+            from: payout.slash_vault.to_account_info(),
+            authority: config.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(payout.token_program.to_account_info(), cpi_accounts, signer);
+        burn(cpi_ctx, burn_amount)?;
+    }
+
+    // Funding through the staking program keeps its reward budget in sync
+    if staker_amount > 0 {
+        let cpi_accounts = axiom_staking::cpi::accounts::FundRewards {
+            pool: payout.staking_pool.to_account_info(),
+            reward_token_account: payout.reward_token_account.clone(),
+            funder_token_account: payout.slash_vault.to_account_info(),
+            reward_token_mint: mint.to_account_info(),
+            funder: config.to_account_info(),
+            token_program: payout.token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(payout.staking_program.to_account_info(), cpi_accounts, signer);
+        axiom_staking::cpi::fund_rewards(cpi_ctx, staker_amount)?;
+    }
+
+    msg!(
+        "Slash split: {} whistleblower, {} burned, {} to stakers, {} to penalty pool",
+        whistleblower_paid, burn_amount, staker_amount, penalty_amount
+    );
+    Ok(())
+}
+
+// Move an appeal bond out of its escrow, signed by the slashing config PDA
+fn release_appeal_bond<'info>(
+    slashing_config: &Account<'info, SlashingConfig>,
+    bond_vault: &InterfaceAccount<'info, TokenAccount>,
+    to: AccountInfo<'info>,
+    staked_token_mint: &InterfaceAccount<'info, Mint>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[b"slashing-config".as_ref(), &[slashing_config.bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: bond_vault.to_account_info(),
+        to,
+        authority: slashing_config.to_account_info(),
+        mint: staked_token_mint.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+    
+    transfer_checked(cpi_ctx, amount, staked_token_mint.decimals)
+}
+
 #[derive(Accounts)]
 pub struct InitializeSlashingConfig<'info> {
     #[account(
@@ -185,6 +858,34 @@ pub struct InitializeSlashingConfig<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct UpdateSlashingConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"slashing-config"],
+        bump = slashing_config.bump,
+        has_one = authority @ SlashingError::Unauthorized
+    )]
+    pub slashing_config: Account<'info, SlashingConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPenaltyPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"slashing-config"],
+        bump = slashing_config.bump,
+        has_one = authority @ SlashingError::Unauthorized
+    )]
+    pub slashing_config: Account<'info, SlashingConfig>,
+    
+    pub penalty_pool: InterfaceAccount<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct SlashAgent<'info> {
     #[account(
@@ -197,95 +898,242 @@ pub struct SlashAgent<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + SlashRecord::INIT_SPACE,
-        seeds = [b"slash-record", user.key().as_ref(), slashing_config.total_slashes.to_le_bytes().as_ref()],
+        space = 8 + SlashProposal::INIT_SPACE,
+        seeds = [b"slash-proposal", user.key().as_ref(), slashing_config.total_slashes.to_le_bytes().as_ref()],
         bump
     )]
-    pub slash_record: Account<'info, SlashRecord>,
+    pub slash_proposal: Account<'info, SlashProposal>,
     
     #[account(
         mut,
         seeds = [b"user-stake", staking_pool.key().as_ref(), user.key().as_ref()],
-        bump
+        bump,
+        seeds::program = staking_program.key()
     )]
     pub user_stake: Account<'info, UserStake>,
     
-    /// CHECK: This account is the staking pool
-    pub staking_pool: AccountInfo<'info>,
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Staker being slashed; no signature is required
+    pub user: AccountInfo<'info>,
+    
+    pub staking_program: Program<'info, AxiomStaking>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AppealSlash<'info> {
+    #[account(
+        seeds = [b"slashing-config"],
+        bump = slashing_config.bump
+    )]
+    pub slashing_config: Account<'info, SlashingConfig>,
     
     #[account(
         mut,
+        constraint = slash_proposal.agent == agent.key() @ SlashingError::Unauthorized
+    )]
+    pub slash_proposal: Account<'info, SlashProposal>,
+    
+    #[account(
+        init,
+        payer = agent,
         token::mint = staked_token_mint,
-        token::authority = user,
+        token::authority = slashing_config,
+        seeds = [b"appeal-bond", slash_proposal.key().as_ref()],
+        bump
     )]
-    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub appeal_bond_vault: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
         mut,
         token::mint = staked_token_mint,
-        token::authority = penalty_pool_authority,
+        token::authority = agent,
     )]
-    pub penalty_pool: InterfaceAccount<'info, TokenAccount>,
+    pub agent_token_account: InterfaceAccount<'info, TokenAccount>,
     
+    #[account(
+        constraint = staked_token_mint.key() == staking_pool.staked_token_mint @ SlashingError::InvalidMint
+    )]
     pub staked_token_mint: InterfaceAccount<'info, Mint>,
     
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    /// CHECK: This account is the penalty pool authority
-    pub penalty_pool_authority: AccountInfo<'info>,
+    #[account(address = slash_proposal.staking_pool)]
+    pub staking_pool: Account<'info, StakingPool>,
     
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub agent: Signer<'info>,
     
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct BurnTokens<'info> {
+pub struct ResolveSlash<'info> {
     #[account(
-        mut,
         seeds = [b"slashing-config"],
-        bump
+        bump = slashing_config.bump
     )]
     pub slashing_config: Account<'info, SlashingConfig>,
     
+    #[account(mut)]
+    pub slash_proposal: Account<'info, SlashProposal>,
+    
+    // An arbiter, or the governance authority (e.g. a realm's native treasury)
+    pub resolver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireAppeal<'info> {
+    #[account(mut)]
+    pub slash_proposal: Account<'info, SlashProposal>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSlash<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + SlashRecord::INIT_SPACE,
-        seeds = [b"slash-record", user.key().as_ref(), slashing_config.total_slashes.to_le_bytes().as_ref()],
+        seeds = [b"slashing-config"],
+        bump = slashing_config.bump
+    )]
+    pub slashing_config: Account<'info, SlashingConfig>,
+    
+    #[account(
+        mut,
+        has_one = staking_pool,
+        constraint = slash_proposal.agent == user.key() @ SlashingError::Unauthorized
+    )]
+    pub slash_proposal: Account<'info, SlashProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"appeal-bond", slash_proposal.key().as_ref()],
         bump
     )]
-    pub slash_record: Account<'info, SlashRecord>,
+    pub appeal_bond_vault: Option<InterfaceAccount<'info, TokenAccount>>,
     
     #[account(
         mut,
         seeds = [b"user-stake", staking_pool.key().as_ref(), user.key().as_ref()],
-        bump
+        bump,
+        seeds::program = staking_program.key()
     )]
     pub user_stake: Account<'info, UserStake>,
     
-    /// CHECK: This account is the staking pool
-    pub staking_pool: AccountInfo<'info>,
+    #[account(mut)]
+    pub staking_pool: Account<'info, StakingPool>,
     
     #[account(
         mut,
         token::mint = staked_token_mint,
-        token::authority = user,
+        token::authority = staking_pool,
     )]
-    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
     
-    pub staked_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = staked_token_mint,
+        token::authority = slashing_config,
+    )]
+    pub slash_vault: InterfaceAccount<'info, TokenAccount>,
     
+    #[account(
+        mut,
+        token::mint = staked_token_mint,
+        token::authority = slash_proposal.reporter,
+    )]
+    pub whistleblower_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Staking pool reward vault, validated by the staking program when funded
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub reward_token_account: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        address = slashing_config.penalty_pool @ SlashingError::InvalidPenaltyPool,
+        token::mint = staked_token_mint,
+    )]
+    pub penalty_pool: InterfaceAccount<'info, TokenAccount>,
     
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub staked_token_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Staker being slashed; no signature is required
+    pub user: AccountInfo<'info>,
+    
+    pub staking_program: Program<'info, AxiomStaking>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct DismissSlash<'info> {
+    #[account(
+        seeds = [b"slashing-config"],
+        bump = slashing_config.bump
+    )]
+    pub slashing_config: Account<'info, SlashingConfig>,
+    
+    #[account(
+        mut,
+        has_one = staking_pool,
+        constraint = slash_proposal.agent == user.key() @ SlashingError::Unauthorized
+    )]
+    pub slash_proposal: Account<'info, SlashProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"user-stake", staking_pool.key().as_ref(), user.key().as_ref()],
+        bump,
+        seeds::program = staking_program.key()
+    )]
+    pub user_stake: Account<'info, UserStake>,
+    
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    /// CHECK: The accused agent, only used to derive its accounts
+    pub user: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"appeal-bond", slash_proposal.key().as_ref()],
+        bump
+    )]
+    pub appeal_bond_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = staked_token_mint,
+        token::authority = slash_proposal.agent,
+    )]
+    pub agent_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub staked_token_mint: InterfaceAccount<'info, Mint>,
     
+    pub staking_program: Program<'info, AxiomStaking>,
     pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SetSlashingPolicy<'info> {
+    #[account(
+        seeds = [b"slashing-config"],
+        bump = slashing_config.bump
+    )]
+    pub slashing_config: Account<'info, SlashingConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + SlashingPolicy::INIT_SPACE,
+        seeds = [b"slashing-policy"],
+        bump
+    )]
+    pub slashing_policy: Account<'info, SlashingPolicy>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -307,41 +1155,67 @@ pub struct AutoSlashForNegativeAttestations<'info> {
     )]
     pub slash_record: Account<'info, SlashRecord>,
     
+    #[account(
+        seeds = [b"slashing-policy"],
+        bump = slashing_policy.bump
+    )]
+    pub slashing_policy: Account<'info, SlashingPolicy>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + OffenseHistory::INIT_SPACE,
+        seeds = [b"offense-history", user.key().as_ref()],
+        bump
+    )]
+    pub offense_history: Account<'info, OffenseHistory>,
+    
     #[account(
         mut,
         seeds = [b"user-stake", staking_pool.key().as_ref(), user.key().as_ref()],
-        bump
+        bump,
+        seeds::program = staking_program.key()
     )]
     pub user_stake: Account<'info, UserStake>,
     
-    /// CHECK: This account is the staking pool
-    pub staking_pool: AccountInfo<'info>,
+    #[account(mut)]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        token::mint = staked_token_mint,
+        token::authority = staking_pool,
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
         mut,
         token::mint = staked_token_mint,
-        token::authority = user,
+        token::authority = slashing_config,
     )]
-    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub slash_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Staking pool reward vault, validated by the staking program when funded
+    #[account(mut)]
+    pub reward_token_account: AccountInfo<'info>,
     
     #[account(
         mut,
+        address = slashing_config.penalty_pool @ SlashingError::InvalidPenaltyPool,
         token::mint = staked_token_mint,
-        token::authority = penalty_pool_authority,
     )]
     pub penalty_pool: InterfaceAccount<'info, TokenAccount>,
     
+    #[account(mut)]
     pub staked_token_mint: InterfaceAccount<'info, Mint>,
     
     #[account(mut)]
     pub authority: Signer<'info>,
     
-    /// CHECK: This account is the penalty pool authority
-    pub penalty_pool_authority: AccountInfo<'info>,
-    
-    #[account(mut)]
-    pub user: Signer<'info>,
+    /// CHECK: Staker being slashed; no signature is required
+    pub user: AccountInfo<'info>,
     
+    pub staking_program: Program<'info, AxiomStaking>,
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
@@ -351,30 +1225,152 @@ pub struct AutoSlashForNegativeAttestations<'info> {
 pub struct SlashingConfig {
     pub authority: Pubkey,
     pub total_slashes: u64,
+    pub challenge_period: i64,      // Seconds an accused agent has to appeal
+    pub appeal_period: i64,         // Seconds an appeal can stay unresolved before it is dismissed
+    pub appeal_bond: u64,           // Counter-bond required to appeal
+    #[max_len(5)]
+    pub arbiters: Vec<Pubkey>,      // Designated arbiters who vote on appeals
+    pub arbiter_threshold: u8,      // Arbiter votes needed to uphold or dismiss
+    pub governance: Pubkey,         // Governance signer that can resolve directly, default disables
+    pub whistleblower_bps: u16,     // Share of each slash paid to the reporter or attesters
+    pub burn_bps: u16,              // Share of each slash burned
+    pub staker_reward_bps: u16,     // Share of each slash added to the staking reward budget
+    #[max_len(4)]
+    pub negative_schemas: Vec<Pubkey>, // Schemas trusted as evidence for automatic slashing
+    pub penalty_pool: Pubkey,       // Token account receiving the penalty share, default disables slashing
     pub bump: u8,
 }
 
+impl SlashingConfig {
+    pub fn share_of(&self, amount: u64, bps: u16) -> u64 {
+        // bps <= BPS_DENOMINATOR, so the share never exceeds amount
+        ((amount as u128) * bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
-pub struct SlashRecord {
-    pub user: Pubkey,
+pub struct SlashProposal {
+    pub agent: Pubkey,
+    pub staking_pool: Pubkey,
+    pub proposer: Pubkey,
+    pub reporter: Pubkey,           // Receives the whistleblower share if the slash executes
     pub amount: u64,
+    #[max_len(200)]
     pub reason: String,
-    pub timestamp: i64,
+    #[max_len(8)]
+    pub evidence: Vec<Pubkey>,      // e.g. attestation accounts backing the report
+    pub created_at: i64,
+    pub challenge_ends_at: i64,
+    pub appeal_ends_at: i64,        // Deadline for resolving an appeal, 0 until appealed
+    pub status: SlashStatus,
+    pub appeal_bond: u64,           // Counter-bond held in escrow while appealed
+    #[max_len(5)]
+    pub arbiter_votes: Vec<Pubkey>,
+    pub uphold_votes: u8,
+    pub dismiss_votes: u8,
+    pub bump: u8,
+}
+
+// Penalties applied by automatic slashing
+#[account]
+#[derive(InitSpace)]
+pub struct SlashingPolicy {
+    #[max_len(8)]
+    pub categories: Vec<OffensePolicy>, // Indexed by offense category
+    pub repeat_multiplier_bps: u16, // Applied once per prior offense in the window
+    pub repeat_window: i64,         // Seconds an offense counts towards escalation
+    pub max_penalty_bps: u16,       // Cap on the share of stake slashed at once
+    pub bump: u8,
+}
+
+impl SlashingPolicy {
+    // Penalty in basis points of the agent's stake. Each negative attestation costs the
+    // category's base penalty, and every prior offense in the window multiplies the total.
+    pub fn penalty_bps(
+        &self,
+        offense: &OffensePolicy,
+        negative_attestations: u64,
+        prior_offenses: u32,
+    ) -> Result<u64> {
+        let max_bps = self.max_penalty_bps as u64;
+        let mut bps = (offense.penalty_bps as u64)
+            .saturating_mul(negative_attestations)
+            .min(max_bps);
+        for _ in 0..prior_offenses {
+            if bps >= max_bps {
+                break;
+            }
+            bps = bps.checked_mul(self.repeat_multiplier_bps as u64)
+                .ok_or(SlashingError::Overflow)?
+                / BPS_DENOMINATOR;
+        }
+        Ok(bps.min(max_bps))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct OffensePolicy {
+    pub penalty_bps: u16,           // Base penalty per negative attestation
+    pub jail_period: i64,           // Seconds the agent is jailed, 0 for none
+}
+
+// Per-agent record of recent offenses
+#[account]
+#[derive(InitSpace)]
+pub struct OffenseHistory {
+    pub agent: Pubkey,
+    #[max_len(16)]
+    pub recent_offenses: Vec<OffenseEntry>, // Oldest first
+    pub total_offenses: u64,
+    pub jailed_until: i64,
     pub bump: u8,
 }
 
-// Import UserStake from the staking program
+impl OffenseHistory {
+    // Forget offenses that have left the rolling window
+    pub fn prune(&mut self, now: i64, window: i64) {
+        self.recent_offenses
+            .retain(|entry| now.saturating_sub(entry.timestamp) < window);
+    }
+
+    pub fn record(&mut self, category: u8, now: i64) -> Result<()> {
+        if self.recent_offenses.len() >= MAX_RECENT_OFFENSES {
+            self.recent_offenses.remove(0);
+        }
+        self.recent_offenses.push(OffenseEntry { category, timestamp: now });
+        self.total_offenses = self.total_offenses.checked_add(1)
+            .ok_or(SlashingError::Overflow)?;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct OffenseEntry {
+    pub category: u8,
+    pub timestamp: i64,
+}
+
+// Slash proposal status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum SlashStatus {
+    Pending,
+    Appealed,
+    Upheld,
+    Dismissed,
+    Executed,
+    Released,                       // Dismissed and settled, the bond and withdrawals are released
+}
+
 #[account]
 #[derive(InitSpace)]
-pub struct UserStake {
+pub struct SlashRecord {
+    pub user: Pubkey,
     pub amount: u64,
-    pub effective_amount: u64,
-    pub reward_debt: u64,
-    pub reputation_score: u64,
-    pub positive_attestations: u64,
-    pub is_cold_start: bool,
-    pub cold_start_timestamp: i64,
+    #[max_len(200)]
+    pub reason: String,
+    pub timestamp: i64,
+    pub bump: u8,
 }
 
 #[error_code]
@@ -387,4 +1383,70 @@ pub enum SlashingError {
     
     #[msg("Insufficient funds")]
     InsufficientFunds,
+    
+    #[msg("Slash reason is too long")]
+    ReasonTooLong,
+    
+    #[msg("Too many evidence accounts")]
+    TooMuchEvidence,
+    
+    #[msg("Invalid dispute configuration")]
+    InvalidDisputeConfig,
+    
+    #[msg("Slash proposal is not in the required status")]
+    InvalidSlashStatus,
+    
+    #[msg("Challenge window has closed")]
+    ChallengeWindowClosed,
+    
+    #[msg("Challenge window is still open")]
+    ChallengeWindowOpen,
+    
+    #[msg("Appeal resolution window has closed")]
+    AppealWindowClosed,
+    
+    #[msg("Appeal resolution window is still open")]
+    AppealWindowOpen,
+    
+    #[msg("Signer is not an arbiter")]
+    NotArbiter,
+    
+    #[msg("Arbiter has already voted")]
+    AlreadyVoted,
+    
+    #[msg("Appeal bond vault is required")]
+    MissingAppealBondVault,
+    
+    #[msg("Mint does not match the staking pool")]
+    InvalidMint,
+    
+    #[msg("Invalid slashing policy")]
+    InvalidSlashingPolicy,
+    
+    #[msg("Unknown offense category")]
+    InvalidOffenseCategory,
+    
+    #[msg("Slash split exceeds 100%")]
+    InvalidSlashSplit,
+    
+    #[msg("Too many trusted schemas")]
+    TooManySchemas,
+    
+    #[msg("No valid attestations were provided")]
+    NoValidAttestations,
+    
+    #[msg("Invalid unbonding ticket")]
+    InvalidUnbondingTicket,
+    
+    #[msg("Every open unbonding ticket is required when the stake cannot cover the slash")]
+    MissingUnbondingTickets,
+    
+    #[msg("Remaining accounts do not match the given counts")]
+    InvalidRemainingAccounts,
+    
+    #[msg("Token account for an attester is missing")]
+    MissingAttesterTokenAccount,
+    
+    #[msg("Penalty pool does not match the slashing config")]
+    InvalidPenaltyPool,
 }
\ No newline at end of file
@@ -3,9 +3,20 @@ use anchor_spl::{
     token_2022::Token2022,
     token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
 };
+use axiom_attestations::{AttestationConfig, AttestationConsumer, Reputation, Sentiment};
 
 declare_id!("3sKxhfHdQgjWBuoztEYonKepba2zGcN2QtWowCmAfWzD");
 
+// Maximum number of trusted attestation schemas per sentiment
+pub const MAX_TRUSTED_SCHEMAS: usize = 4;
+
+// Reputation at which a reputation stake counts double
+pub const MAX_REPUTATION: u64 = 10_000;
+
+// Boost per positive attestation, and the cap on the total boost, in basis points of the stake
+pub const ATTESTATION_BOOST_BPS: u64 = 10;
+pub const MAX_ATTESTATION_BOOST_BPS: u64 = 1_000;
+
 #[program]
 pub mod axiom_staking {
     use super::*;
@@ -19,6 +30,19 @@ pub mod axiom_staking {
         pool.total_staked = 0;
         pool.acc_reward_per_share = 0;
         pool.last_reward_time = Clock::get()?.unix_timestamp;
+        // Emissions run until scheduled otherwise, bounded by the funded rewards
+        pool.emissions_end = i64::MAX;
+        pool.total_rewards_funded = 0;
+        pool.total_rewards_accrued = 0;
+        pool.unbonding_period = StakingPool::DEFAULT_UNBONDING_PERIOD;
+        pool.total_unbonding = 0;
+        // The pool authority can slash until a slashing program is configured
+        pool.slash_authority = ctx.accounts.authority.key();
+        pool.positive_attestations = 0;
+        pool.negative_attestations = 0;
+        pool.dynamic_apr = 0;
+        pool.positive_schemas = Vec::new();
+        pool.negative_schemas = Vec::new();
         
         Ok(())
     }
@@ -37,24 +61,28 @@ pub mod axiom_staking {
         
         transfer_checked(cpi_ctx, amount, ctx.accounts.staked_token_mint.decimals)?;
         
-        // Update user stake account
+        let pool = &mut ctx.accounts.pool;
+        pool.update_pool(Clock::get()?.unix_timestamp)?;
+        
+        // Update user stake account, a plain stake counts at face value
         let user_stake = &mut ctx.accounts.user_stake;
         user_stake.amount = user_stake.amount.checked_add(amount)
             .ok_or(StakingError::Overflow)?;
-        user_stake.reward_debt = user_stake.reward_debt.checked_add(
-            ((amount as u64).checked_mul(ctx.accounts.pool.acc_reward_per_share as u64)
-                .ok_or(StakingError::Overflow)?) as u64
-        ).ok_or(StakingError::Overflow)?;
+        user_stake.effective_amount = user_stake.effective_amount.checked_add(amount)
+            .ok_or(StakingError::Overflow)?;
+        user_stake.reward_debt = pool.accumulated_reward(user_stake.effective_amount)?;
         
         // Update pool
-        let pool = &mut ctx.accounts.pool;
         pool.total_staked = pool.total_staked.checked_add(amount)
             .ok_or(StakingError::Overflow)?;
+        pool.total_effective_staked = pool.total_effective_staked.checked_add(amount)
+            .ok_or(StakingError::Overflow)?;
         
         Ok(())
     }
 
     pub fn unstake_tokens(ctx: Context<UnstakeTokens>, amount: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
         let user_stake = &mut ctx.accounts.user_stake;
         
         // Check if user has enough staked
@@ -62,33 +90,30 @@ pub mod axiom_staking {
             return err!(StakingError::InsufficientStakedAmount);
         }
 
-        // Calculate pending rewards
-        let pending_reward = (((user_stake.amount as u64)
-            .checked_mul(ctx.accounts.pool.acc_reward_per_share as u64)
-            .ok_or(StakingError::Overflow)?) as u64)
-            .checked_sub(user_stake.reward_debt)
-            .ok_or(StakingError::Overflow)?;
-
-        // Transfer staked tokens back to user
-        let staked_token_mint_key = ctx.accounts.staked_token_mint.key();
-        let seeds = &[
-            b"staking-pool",
-            staked_token_mint_key.as_ref(),
-            &[ctx.bumps.pool],
-        ];
-        let signer = &[&seeds[..]];
+        // Calculate pending rewards; a jailed staker forfeits them to the reward budget
+        let now = Clock::get()?.unix_timestamp;
+        pool.update_pool(now)?;
+        let mut pending_reward = user_stake.pending_reward(pool)?;
+        if user_stake.is_jailed(now) {
+            pool.total_rewards_accrued = pool.total_rewards_accrued.saturating_sub(pending_reward);
+            pending_reward = 0;
+        }
 
-        let cpi_accounts = TransferChecked {
-            from: ctx.accounts.pool_token_account.to_account_info(),
-            to: ctx.accounts.user_token_account.to_account_info(),
-            authority: ctx.accounts.pool.to_account_info(),
-            mint: ctx.accounts.staked_token_mint.to_account_info(),
-        };
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        // Principal stays in the pool vault, and slashable, until the ticket unlocks
+        let ticket = &mut ctx.accounts.unbonding_ticket;
+        ticket.owner = ctx.accounts.user.key();
+        ticket.pool = pool.key();
+        ticket.amount = amount;
+        ticket.unlock_time = now.checked_add(pool.unbonding_period)
+            .ok_or(StakingError::Overflow)?;
+        ticket.bump = *ctx.bumps.get("unbonding_ticket").unwrap();
         
-        transfer_checked(cpi_ctx, amount, ctx.accounts.staked_token_mint.decimals)?;
+        user_stake.unbonding_count = user_stake.unbonding_count.checked_add(1)
+            .ok_or(StakingError::Overflow)?;
+        user_stake.open_unbonding_tickets = user_stake.open_unbonding_tickets.checked_add(1)
+            .ok_or(StakingError::Overflow)?;
+        pool.total_unbonding = pool.total_unbonding.checked_add(amount)
+            .ok_or(StakingError::Overflow)?;
 
         // Transfer rewards to user
         if pending_reward > 0 {
@@ -96,7 +121,7 @@ pub mod axiom_staking {
             let seeds = &[
                 b"staking-pool",
                 staked_token_mint_key.as_ref(),
-                &[ctx.bumps.pool],
+                &[*ctx.bumps.get("pool").unwrap()],
             ];
             let signer = &[&seeds[..]];
 
@@ -113,37 +138,232 @@ pub mod axiom_staking {
             transfer_checked(cpi_ctx, pending_reward, ctx.accounts.reward_token_mint.decimals)?;
         }
 
-        // Update user stake account
+        // Update user stake account, the effective amount shrinks pro rata
+        let user_stake = &mut ctx.accounts.user_stake;
+        let effective_removed = user_stake.effective_share(amount)?;
         user_stake.amount = user_stake.amount.checked_sub(amount)
             .ok_or(StakingError::Overflow)?;
-        user_stake.reward_debt = ((user_stake.amount as u64)
-            .checked_mul(ctx.accounts.pool.acc_reward_per_share as u64)
-            .ok_or(StakingError::Overflow)?) as u64;
+        user_stake.effective_amount = user_stake.effective_amount.checked_sub(effective_removed)
+            .ok_or(StakingError::Overflow)?;
 
         // Update pool
         let pool = &mut ctx.accounts.pool;
+        user_stake.reward_debt = pool.accumulated_reward(user_stake.effective_amount)?;
         pool.total_staked = pool.total_staked.checked_sub(amount)
             .ok_or(StakingError::Overflow)?;
+        pool.total_effective_staked = pool.total_effective_staked.checked_sub(effective_removed)
+            .ok_or(StakingError::Overflow)?;
+
+        msg!("Unbonding {} tokens until {}", amount, ctx.accounts.unbonding_ticket.unlock_time);
+        Ok(())
+    }
+
+    // Return the principal of an unbonding ticket once its cooldown has passed
+    // Blocked while a slash against the staker is still open, so the appeal process
+    // cannot outlast the unbonding period
+    pub fn withdraw_unbonded(ctx: Context<WithdrawUnbonded>) -> Result<()> {
+        let ticket = &ctx.accounts.unbonding_ticket;
+        
+        require!(
+            Clock::get()?.unix_timestamp >= ticket.unlock_time,
+            StakingError::UnbondingPeriodNotElapsed
+        );
+        require!(ctx.accounts.user_stake.open_slashes == 0, StakingError::SlashPending);
+        
+        let amount = ticket.amount;
+        if amount > 0 {
+            let staked_token_mint_key = ctx.accounts.staked_token_mint.key();
+            let seeds = &[
+                b"staking-pool",
+                staked_token_mint_key.as_ref(),
+                &[*ctx.bumps.get("pool").unwrap()],
+            ];
+            let signer = &[&seeds[..]];
+
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.pool_token_account.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+                mint: ctx.accounts.staked_token_mint.to_account_info(),
+            };
+            
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            
+            transfer_checked(cpi_ctx, amount, ctx.accounts.staked_token_mint.decimals)?;
+        }
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.total_unbonding = pool.total_unbonding.checked_sub(amount)
+            .ok_or(StakingError::Overflow)?;
+        let user_stake = &mut ctx.accounts.user_stake;
+        user_stake.open_unbonding_tickets = user_stake.open_unbonding_tickets.saturating_sub(1);
+        
+        msg!("Withdrew {} unbonded tokens", amount);
+        Ok(())
+    }
+    
+    // Seize staked tokens from the pool vault, taking from an unbonding ticket
+    // once the active stake is exhausted
+    pub fn slash(ctx: Context<Slash>, amount: u64) -> Result<()> {
+        require!(amount > 0, StakingError::InvalidAmount);
+        
+        let pool = &mut ctx.accounts.pool;
+        let user_stake = &mut ctx.accounts.user_stake;
+        
+        let from_stake = amount.min(user_stake.amount);
+        let from_ticket = amount - from_stake;
+        
+        if from_stake > 0 {
+            pool.update_pool(Clock::get()?.unix_timestamp)?;
+            
+            // Unclaimed rewards are forfeited and returned to the reward budget
+            let forfeited = user_stake.pending_reward(pool)?;
+            pool.total_rewards_accrued = pool.total_rewards_accrued.saturating_sub(forfeited);
+            
+            let effective_removed = user_stake.effective_share(from_stake)?;
+            user_stake.amount = user_stake.amount.checked_sub(from_stake)
+                .ok_or(StakingError::Overflow)?;
+            user_stake.effective_amount = user_stake.effective_amount.checked_sub(effective_removed)
+                .ok_or(StakingError::Overflow)?;
+            user_stake.reward_debt = pool.accumulated_reward(user_stake.effective_amount)?;
+            
+            pool.total_staked = pool.total_staked.checked_sub(from_stake)
+                .ok_or(StakingError::Overflow)?;
+            pool.total_effective_staked = pool.total_effective_staked.checked_sub(effective_removed)
+                .ok_or(StakingError::Overflow)?;
+        }
+        
+        if from_ticket > 0 {
+            let ticket = ctx.accounts.unbonding_ticket.as_mut()
+                .ok_or(StakingError::InsufficientStakedAmount)?;
+            ticket.amount = ticket.amount.checked_sub(from_ticket)
+                .ok_or(StakingError::InsufficientStakedAmount)?;
+            pool.total_unbonding = pool.total_unbonding.checked_sub(from_ticket)
+                .ok_or(StakingError::Overflow)?;
+        }
+        
+        let staked_token_mint_key = ctx.accounts.staked_token_mint.key();
+        let seeds = &[
+            b"staking-pool",
+            staked_token_mint_key.as_ref(),
+            &[*ctx.bumps.get("pool").unwrap()],
+        ];
+        let signer = &[&seeds[..]];
 
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.pool_token_account.to_account_info(),
+            to: ctx.accounts.penalty_token_account.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+            mint: ctx.accounts.staked_token_mint.to_account_info(),
+        };
+        
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        
+        transfer_checked(cpi_ctx, amount, ctx.accounts.staked_token_mint.decimals)?;
+        
+        msg!("Slashed {} staked tokens from {}", amount, ctx.accounts.staker.key());
+        Ok(())
+    }
+    
+    // Track slash proposals opened and settled against a staker; withdrawals of
+    // unbonded tokens are held while any are open
+    pub fn hold_withdrawals(ctx: Context<HoldWithdrawals>, hold: bool) -> Result<()> {
+        let user_stake = &mut ctx.accounts.user_stake;
+        user_stake.open_slashes = if hold {
+            user_stake.open_slashes.checked_add(1).ok_or(StakingError::Overflow)?
+        } else {
+            user_stake.open_slashes.saturating_sub(1)
+        };
+        
+        msg!("{} open slashes against {}", user_stake.open_slashes, ctx.accounts.staker.key());
+        Ok(())
+    }
+    
+    // Bar a staker from receiving rewards until the given time
+    pub fn jail(ctx: Context<Jail>, jailed_until: i64) -> Result<()> {
+        let user_stake = &mut ctx.accounts.user_stake;
+        // Never shorten a jail that is already running
+        user_stake.jailed_until = user_stake.jailed_until.max(jailed_until);
+        
+        msg!("Jailed {} until {}", ctx.accounts.staker.key(), user_stake.jailed_until);
+        Ok(())
+    }
+    
+    // Set which attestation schemas count as positive and negative signals
+    pub fn set_trusted_schemas(
+        ctx: Context<UpdateRewardRate>,
+        positive_schemas: Vec<Pubkey>,
+        negative_schemas: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.pool.authority,
+            StakingError::Unauthorized
+        );
+        require!(
+            positive_schemas.len() <= MAX_TRUSTED_SCHEMAS && negative_schemas.len() <= MAX_TRUSTED_SCHEMAS,
+            StakingError::InvalidTrustedSchemas
+        );
+        // A schema cannot count both ways
+        require!(
+            !positive_schemas.iter().any(|schema| negative_schemas.contains(schema)),
+            StakingError::InvalidTrustedSchemas
+        );
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.positive_schemas = positive_schemas;
+        pool.negative_schemas = negative_schemas;
+        
+        msg!("Updated trusted attestation schemas");
+        Ok(())
+    }
+    
+    // Set who may call slash, normally the slashing program's config PDA
+    pub fn set_slash_authority(ctx: Context<UpdateRewardRate>, slash_authority: Pubkey) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.pool.authority,
+            StakingError::Unauthorized
+        );
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.slash_authority = slash_authority;
+        
+        msg!("Updated slash authority to {}", slash_authority);
+        Ok(())
+    }
+    
+    // Set how long unstaked tokens stay in the pool before they can be withdrawn
+    pub fn set_unbonding_period(ctx: Context<UpdateRewardRate>, unbonding_period: i64) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.pool.authority,
+            StakingError::Unauthorized
+        );
+        require!(unbonding_period >= 0, StakingError::InvalidUnbondingPeriod);
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.unbonding_period = unbonding_period;
+        
+        msg!("Updated unbonding period to {} seconds", unbonding_period);
         Ok(())
     }
 
     pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
         let user_stake = &mut ctx.accounts.user_stake;
+        let now = Clock::get()?.unix_timestamp;
+        require!(!user_stake.is_jailed(now), StakingError::StakerJailed);
         
         // Calculate pending rewards
-        let pending_reward = (((user_stake.amount as u64)
-            .checked_mul(ctx.accounts.pool.acc_reward_per_share as u64)
-            .ok_or(StakingError::Overflow)?) as u64)
-            .checked_sub(user_stake.reward_debt)
-            .ok_or(StakingError::Overflow)?;
+        pool.update_pool(now)?;
+        let pending_reward = user_stake.pending_reward(pool)?;
 
         if pending_reward > 0 {
             let staked_token_mint_key = ctx.accounts.staked_token_mint.key();
             let seeds = &[
                 b"staking-pool",
                 staked_token_mint_key.as_ref(),
-                &[ctx.bumps.pool],
+                &[*ctx.bumps.get("pool").unwrap()],
             ];
             let signer = &[&seeds[..]];
 
@@ -161,15 +381,70 @@ pub mod axiom_staking {
         }
 
         // Update user stake account
-        user_stake.reward_debt = ((user_stake.amount as u64)
-            .checked_mul(ctx.accounts.pool.acc_reward_per_share as u64)
-            .ok_or(StakingError::Overflow)?) as u64;
+        let user_stake = &mut ctx.accounts.user_stake;
+        user_stake.reward_debt = ctx.accounts.pool.accumulated_reward(user_stake.effective_amount)?;
 
         Ok(())
     }
     
+    // Top up the reward vault, anyone can fund a pool
+    pub fn fund_rewards(ctx: Context<FundRewards>, amount: u64) -> Result<()> {
+        require!(amount > 0, StakingError::InvalidAmount);
+        
+        // Accrue up to now so earlier depleted time is not paid from the new funds
+        let pool = &mut ctx.accounts.pool;
+        pool.update_pool(Clock::get()?.unix_timestamp)?;
+        
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.funder_token_account.to_account_info(),
+            to: ctx.accounts.reward_token_account.to_account_info(),
+            authority: ctx.accounts.funder.to_account_info(),
+            mint: ctx.accounts.reward_token_mint.to_account_info(),
+        };
+        
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        
+        transfer_checked(cpi_ctx, amount, ctx.accounts.reward_token_mint.decimals)?;
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.total_rewards_funded = pool.total_rewards_funded.checked_add(amount)
+            .ok_or(StakingError::Overflow)?;
+        
+        msg!("Funded {} reward tokens ({} remaining)", amount, pool.rewards_remaining());
+        Ok(())
+    }
+    
+    // Set the reward rate and the time emissions stop
+    pub fn schedule_emissions(ctx: Context<UpdateRewardRate>, reward_rate: u64, emissions_end: i64) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.pool.authority,
+            StakingError::Unauthorized
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        require!(emissions_end > now, StakingError::InvalidEmissionSchedule);
+        
+        // Settle rewards earned under the old schedule first
+        let pool = &mut ctx.accounts.pool;
+        pool.update_pool(now)?;
+        pool.reward_rate = reward_rate;
+        pool.emissions_end = emissions_end;
+        
+        msg!("Scheduled emissions of {} per second until {}", reward_rate, emissions_end);
+        Ok(())
+    }
+    
     // New function for reputation-based staking (RaY)
-    pub fn stake_with_reputation(ctx: Context<StakeWithReputation>, amount: u64, reputation_score: u64) -> Result<()> {
+    // The reputation score is read from the user's attestation ledger entry
+    pub fn stake_with_reputation(ctx: Context<StakeWithReputation>, amount: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let reputation_score = ledger_reputation(
+            &ctx.accounts.reputation,
+            &ctx.accounts.attestation_config,
+            now,
+        );
+        
         // Transfer tokens from user to pool
         let cpi_accounts = TransferChecked {
             from: ctx.accounts.user_token_account.to_account_info(),
@@ -185,11 +460,18 @@ pub mod axiom_staking {
         
         // Calculate reputation multiplier (1.0 to 2.0 based on reputation score)
         // For simplicity, we'll use a linear scale from 1.0 (0 reputation) to 2.0 (max reputation)
-        let max_reputation = 10000u64; // Example max reputation score
-        let reputation_multiplier = 1.0 + (reputation_score as f64 / max_reputation as f64);
+        let max_reputation = MAX_REPUTATION as u128;
         
         // Apply reputation multiplier to the staked amount for reward calculations
-        let effective_amount = (amount as f64 * reputation_multiplier) as u64;
+        let effective_amount = u64::try_from(
+            (amount as u128)
+                .checked_mul(max_reputation + reputation_score as u128)
+                .ok_or(StakingError::Overflow)?
+                / max_reputation
+        ).map_err(|_| StakingError::Overflow)?;
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.update_pool(now)?;
         
         // Update user stake account
         let user_stake = &mut ctx.accounts.user_stake;
@@ -197,14 +479,10 @@ pub mod axiom_staking {
             .ok_or(StakingError::Overflow)?;
         user_stake.effective_amount = user_stake.effective_amount.checked_add(effective_amount)
             .ok_or(StakingError::Overflow)?;
-        user_stake.reward_debt = user_stake.reward_debt.checked_add(
-            ((effective_amount as u64).checked_mul(ctx.accounts.pool.acc_reward_per_share as u64)
-                .ok_or(StakingError::Overflow)?) as u64
-        ).ok_or(StakingError::Overflow)?;
+        user_stake.reward_debt = pool.accumulated_reward(user_stake.effective_amount)?;
         user_stake.reputation_score = reputation_score;
         
         // Update pool
-        let pool = &mut ctx.accounts.pool;
         pool.total_staked = pool.total_staked.checked_add(amount)
             .ok_or(StakingError::Overflow)?;
         pool.total_effective_staked = pool.total_effective_staked.checked_add(effective_amount)
@@ -222,7 +500,9 @@ pub mod axiom_staking {
             StakingError::Unauthorized
         );
         
+        // Settle rewards earned at the old rate first
         let pool = &mut ctx.accounts.pool;
+        pool.update_pool(Clock::get()?.unix_timestamp)?;
         pool.reward_rate = new_reward_rate;
         
         msg!("Updated reward rate to {}", new_reward_rate);
@@ -230,8 +510,39 @@ pub mod axiom_staking {
     }
     
     // New function to calculate dynamic APY based on SAS attestations
-    pub fn calculate_dynamic_apr(ctx: Context<CalculateDynamicAPR>, positive_attestations: u64, negative_attestations: u64) -> Result<()> {
+    // Attestations about the pool are passed as (attestation, marker) pairs in
+    // remaining_accounts; each verified attestation is counted once
+    pub fn calculate_dynamic_apr<'info>(ctx: Context<'_, '_, '_, 'info, CalculateDynamicAPR<'info>>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let trusted_schemas = pool.trusted_schemas();
+        let counted = AttestationConsumer {
+            program_id: ctx.program_id,
+            scope: pool.key(),
+            subject: pool.key(),
+            trusted_schemas: &trusted_schemas,
+            sentiment: None,
+            payer: ctx.accounts.authority.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        }.consume(ctx.remaining_accounts)?;
+        
         let pool = &mut ctx.accounts.pool;
+        // Each attestation counts as many times as its weight
+        for attestation in counted.iter() {
+            let weight = attestation.weight as u64;
+            match attestation.sentiment {
+                Sentiment::Positive => {
+                    pool.positive_attestations = pool.positive_attestations.checked_add(weight)
+                        .ok_or(StakingError::Overflow)?;
+                }
+                Sentiment::Negative => {
+                    pool.negative_attestations = pool.negative_attestations.checked_add(weight)
+                        .ok_or(StakingError::Overflow)?;
+                }
+                Sentiment::Neutral => {}
+            }
+        }
+        let positive_attestations = pool.positive_attestations;
+        let negative_attestations = pool.negative_attestations;
         
         // Calculate base APR (e.g., 10%)
         let base_apr = 1000u64; // 10% in basis points (1000/10000 = 10%)
@@ -245,15 +556,11 @@ pub mod axiom_staking {
         let new_apr = base_apr
             .saturating_add(positive_multiplier)
             .saturating_sub(negative_multiplier)
-            .max(100) // Minimum 1%
-            .min(5000); // Maximum 50%
-        
-        // Convert APR to reward rate (simplified calculation)
-        // In a real implementation, this would consider time periods and compounding
-        let new_reward_rate = new_apr.saturating_mul(100); // Scale for precision
+            .clamp(100, 5000); // Minimum 1%, maximum 50%
         
-        // Update the pool reward rate
-        pool.reward_rate = new_reward_rate;
+        // Record the APR only. The emission rate stays with the authority's
+        // schedule_emissions, which is bounded by the funded rewards.
+        pool.dynamic_apr = new_apr;
         
         msg!("Calculated dynamic APR: {} basis points ({}%) based on {} positive and {} negative attestations", 
              new_apr, new_apr as f64 / 100.0, positive_attestations, negative_attestations);
@@ -261,66 +568,93 @@ pub mod axiom_staking {
         Ok(())
     }
     
-    // New function to apply reputation boost multipliers for positive SAS attestations
-    pub fn apply_reputation_boost(ctx: Context<ApplyReputationBoost>, positive_attestations: u64) -> Result<()> {
-        let user_stake = &mut ctx.accounts.user_stake;
+    // New function to apply reputation boost multipliers for positive SAS attestations.
+    // Attestations about the user are passed as (attestation, marker) pairs in
+    // remaining_accounts; each verified attestation boosts the stake once.
+    pub fn apply_reputation_boost<'info>(ctx: Context<'_, '_, '_, 'info, ApplyReputationBoost<'info>>) -> Result<()> {
+        let counted = AttestationConsumer {
+            program_id: ctx.program_id,
+            scope: ctx.accounts.user_stake.key(),
+            subject: ctx.accounts.user.key(),
+            trusted_schemas: &ctx.accounts.pool.positive_schemas,
+            sentiment: Some(Sentiment::Positive),
+            payer: ctx.accounts.user.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        }.consume(ctx.remaining_accounts)?;
+        // Each attestation counts as many times as its weight
+        let positive_attestations: u64 = counted.iter().map(|attestation| attestation.weight as u64).sum();
+        require!(positive_attestations > 0, StakingError::NoValidAttestations);
         
-        // Calculate boost multiplier based on positive attestations
-        // Each positive attestation provides a 0.1% boost, capped at 10%
-        let boost_percentage = (positive_attestations as f64 * 0.1).min(10.0);
-        let boost_multiplier = 1.0 + (boost_percentage / 100.0);
+        let user_stake = &mut ctx.accounts.user_stake;
         
-        // Apply boost to effective amount
-        let new_effective_amount = ((user_stake.effective_amount as f64) * boost_multiplier) as u64;
+        // Each positive attestation boosts the staked amount by 0.1%, and the boosts from
+        // all attestations together are capped at 10% of the staked amount
+        let total_attestations = user_stake.positive_attestations.checked_add(positive_attestations)
+            .ok_or(StakingError::Overflow)?;
+        let boost_bps = |attestations: u64| {
+            attestations.saturating_mul(ATTESTATION_BOOST_BPS).min(MAX_ATTESTATION_BOOST_BPS)
+        };
+        let added_bps = boost_bps(total_attestations) - boost_bps(user_stake.positive_attestations);
+        let effective_amount_diff = u64::try_from(
+            (user_stake.amount as u128) * (added_bps as u128) / 10_000
+        ).map_err(|_| StakingError::Overflow)?;
         
         // Update pool's total effective staked amount
         let pool = &mut ctx.accounts.pool;
-        let effective_amount_diff = new_effective_amount.saturating_sub(user_stake.effective_amount);
+        pool.update_pool(Clock::get()?.unix_timestamp)?;
         pool.total_effective_staked = pool.total_effective_staked.checked_add(effective_amount_diff)
             .ok_or(StakingError::Overflow)?;
         
         // Update user stake
-        user_stake.effective_amount = new_effective_amount;
-        user_stake.positive_attestations = user_stake.positive_attestations.checked_add(positive_attestations)
+        user_stake.effective_amount = user_stake.effective_amount.checked_add(effective_amount_diff)
             .ok_or(StakingError::Overflow)?;
+        user_stake.positive_attestations = total_attestations;
         
-        // Update reward debt to maintain consistency
+        // Update reward debt so the boost only applies to rewards from now on
         user_stake.reward_debt = user_stake.reward_debt.checked_add(
-            ((effective_amount_diff as u64).checked_mul(pool.acc_reward_per_share as u64)
-                .ok_or(StakingError::Overflow)?) as u64
+            pool.accumulated_reward(effective_amount_diff)?
         ).ok_or(StakingError::Overflow)?;
         
-        msg!("Applied reputation boost of {}% based on {} positive attestations", boost_percentage, positive_attestations);
+        msg!("Applied reputation boost of {} bps based on {} positive attestations", added_bps, positive_attestations);
         Ok(())
     }
     
-    // New function to initialize cold-start trust for new agents
-    pub fn initialize_cold_start_trust(ctx: Context<InitializeColdStartTrust>, initial_trust_score: u64) -> Result<()> {
-        let user_stake = &mut ctx.accounts.user_stake;
+    // New function to initialize cold-start trust for new agents.
+    // New agents have no reputation yet, so the deposit counts at face value.
+    pub fn initialize_cold_start_trust(ctx: Context<InitializeColdStartTrust>, amount: u64) -> Result<()> {
+        // Transfer tokens from user to pool
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.user_token_account.to_account_info(),
+            to: ctx.accounts.pool_token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+            mint: ctx.accounts.staked_token_mint.to_account_info(),
+        };
+        
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         
-        // For new agents, we provide a small initial trust score to help them get started
-        // This is a cold-start mechanism to bootstrap trust for new agents
-        let base_amount = 1000000u64; // 1 token as base amount
-        let trust_multiplier = (initial_trust_score as f64 / 100.0).max(0.1).min(2.0); // 0.1 to 2.0 multiplier
-        let effective_amount = (base_amount as f64 * trust_multiplier) as u64;
+        transfer_checked(cpi_ctx, amount, ctx.accounts.staked_token_mint.decimals)?;
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.update_pool(Clock::get()?.unix_timestamp)?;
         
         // Initialize user stake with cold-start trust
-        user_stake.amount = base_amount;
-        user_stake.effective_amount = effective_amount;
-        user_stake.reward_debt = 0;
-        user_stake.reputation_score = initial_trust_score;
+        let user_stake = &mut ctx.accounts.user_stake;
+        user_stake.amount = amount;
+        user_stake.effective_amount = amount;
+        user_stake.reward_debt = pool.accumulated_reward(amount)?;
+        user_stake.reputation_score = 0;
         user_stake.positive_attestations = 0;
         user_stake.is_cold_start = true;
         user_stake.cold_start_timestamp = Clock::get()?.unix_timestamp;
         
         // Update pool statistics
-        let pool = &mut ctx.accounts.pool;
-        pool.total_staked = pool.total_staked.checked_add(base_amount)
+        pool.total_staked = pool.total_staked.checked_add(amount)
             .ok_or(StakingError::Overflow)?;
-        pool.total_effective_staked = pool.total_effective_staked.checked_add(effective_amount)
+        pool.total_effective_staked = pool.total_effective_staked.checked_add(amount)
             .ok_or(StakingError::Overflow)?;
         
-        msg!("Initialized cold-start trust with score {} (effective amount: {})", initial_trust_score, effective_amount);
+        msg!("Initialized cold-start trust with a deposit of {}", amount);
         Ok(())
     }
     
@@ -343,8 +677,13 @@ pub mod axiom_staking {
         Ok(())
     }
     
-    // New function to update user reputation score directly
-    pub fn update_reputation_score(ctx: Context<UpdateReputationScore>, new_reputation: u64) -> Result<()> {
+    // Refresh the stored reputation score from the user's attestation ledger entry
+    pub fn update_reputation_score(ctx: Context<UpdateReputationScore>) -> Result<()> {
+        let new_reputation = ledger_reputation(
+            &ctx.accounts.reputation,
+            &ctx.accounts.attestation_config,
+            Clock::get()?.unix_timestamp,
+        );
         let user_stake = &mut ctx.accounts.user_stake;
         user_stake.reputation_score = new_reputation;
         
@@ -430,6 +769,67 @@ pub struct UnstakeTokens<'info> {
     )]
     pub user_stake: Account<'info, UserStake>,
     
+    #[account(
+        init,
+        payer = user,
+        space = 8 + UnbondingTicket::INIT_SPACE,
+        seeds = [
+            b"unbonding-ticket",
+            user_stake.key().as_ref(),
+            &user_stake.unbonding_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub unbonding_ticket: Account<'info, UnbondingTicket>,
+    
+    #[account(
+        mut,
+        token::mint = reward_token_mint,
+        token::authority = pool,
+    )]
+    pub reward_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = reward_token_mint,
+        token::authority = user,
+    )]
+    pub user_reward_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub staked_token_mint: InterfaceAccount<'info, Mint>,
+    pub reward_token_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawUnbonded<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking-pool", staked_token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"user-stake", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_stake: Account<'info, UserStake>,
+    
+    #[account(
+        mut,
+        close = user,
+        has_one = pool,
+        constraint = unbonding_ticket.owner == user.key() @ StakingError::Unauthorized
+    )]
+    pub unbonding_ticket: Account<'info, UnbondingTicket>,
+    
     #[account(
         mut,
         token::mint = staked_token_mint,
@@ -444,28 +844,103 @@ pub struct UnstakeTokens<'info> {
     )]
     pub user_token_account: InterfaceAccount<'info, TokenAccount>,
     
+    pub staked_token_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct Slash<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking-pool", staked_token_mint.key().as_ref()],
+        bump,
+        has_one = slash_authority @ StakingError::Unauthorized
+    )]
+    pub pool: Account<'info, StakingPool>,
+    
     #[account(
         mut,
-        token::mint = reward_token_mint,
+        seeds = [b"user-stake", pool.key().as_ref(), staker.key().as_ref()],
+        bump
+    )]
+    pub user_stake: Account<'info, UserStake>,
+    
+    #[account(
+        mut,
+        has_one = pool,
+        constraint = unbonding_ticket.owner == staker.key() @ StakingError::Unauthorized
+    )]
+    pub unbonding_ticket: Option<Account<'info, UnbondingTicket>>,
+    
+    /// CHECK: Wallet that owns the stake, only used to derive its accounts
+    pub staker: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        token::mint = staked_token_mint,
         token::authority = pool,
     )]
-    pub reward_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
         mut,
-        token::mint = reward_token_mint,
-        token::authority = user,
+        token::mint = staked_token_mint,
     )]
-    pub user_reward_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub penalty_token_account: InterfaceAccount<'info, TokenAccount>,
     
     pub staked_token_mint: InterfaceAccount<'info, Mint>,
-    pub reward_token_mint: InterfaceAccount<'info, Mint>,
     
-    #[account(mut)]
-    pub user: Signer<'info>,
+    pub slash_authority: Signer<'info>,
     
     pub token_program: Program<'info, Token2022>,
-    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Jail<'info> {
+    #[account(
+        seeds = [b"staking-pool", pool.staked_token_mint.as_ref()],
+        bump,
+        has_one = slash_authority @ StakingError::Unauthorized
+    )]
+    pub pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"user-stake", pool.key().as_ref(), staker.key().as_ref()],
+        bump
+    )]
+    pub user_stake: Account<'info, UserStake>,
+    
+    /// CHECK: Wallet that owns the stake, only used to derive its accounts
+    pub staker: AccountInfo<'info>,
+    
+    pub slash_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct HoldWithdrawals<'info> {
+    #[account(
+        seeds = [b"staking-pool", pool.staked_token_mint.as_ref()],
+        bump,
+        has_one = slash_authority @ StakingError::Unauthorized
+    )]
+    pub pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"user-stake", pool.key().as_ref(), staker.key().as_ref()],
+        bump
+    )]
+    pub user_stake: Account<'info, UserStake>,
+    
+    /// CHECK: Wallet that owns the stake, only used to derive its accounts
+    pub staker: AccountInfo<'info>,
+    
+    pub slash_authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -546,16 +1021,61 @@ pub struct StakeWithReputation<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
+    #[account(
+        seeds = [b"reputation", user.key().as_ref()],
+        bump = reputation.bump,
+        seeds::program = axiom_attestations::ID
+    )]
+    pub reputation: Account<'info, Reputation>,
+    
+    #[account(
+        seeds = [b"attestation-config"],
+        bump = attestation_config.bump,
+        seeds::program = axiom_attestations::ID
+    )]
+    pub attestation_config: Account<'info, AttestationConfig>,
+    
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct FundRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking-pool", pool.staked_token_mint.as_ref()],
+        bump,
+        has_one = reward_token_mint
+    )]
+    pub pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        token::mint = reward_token_mint,
+        token::authority = pool,
+    )]
+    pub reward_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = reward_token_mint,
+        token::authority = funder,
+    )]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub reward_token_mint: InterfaceAccount<'info, Mint>,
+    
+    pub funder: Signer<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
 // New account context for updating reward rate
 #[derive(Accounts)]
 pub struct UpdateRewardRate<'info> {
     #[account(
         mut,
-        seeds = [b"staking-pool", staked_token_mint.key().as_ref()],
+        seeds = [b"staking-pool", pool.staked_token_mint.as_ref()],
         bump
     )]
     pub pool: Account<'info, StakingPool>,
@@ -575,13 +1095,13 @@ pub struct CalculateDynamicAPR<'info> {
     )]
     pub pool: Account<'info, StakingPool>,
     
-    /// CHECK: This account can be the attestation program or an authorized entity
-    pub attestation_authority: AccountInfo<'info>,
-    
     pub staked_token_mint: InterfaceAccount<'info, Mint>,
     
+    // Pays for the consumed-attestation markers
     #[account(mut)]
     pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 // New account context for applying reputation boost
@@ -606,8 +1126,7 @@ pub struct ApplyReputationBoost<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
-    /// CHECK: This account can be the attestation program or an authorized entity
-    pub attestation_authority: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 // New account context for initializing cold-start trust
@@ -629,11 +1148,26 @@ pub struct InitializeColdStartTrust<'info> {
     )]
     pub user_stake: Account<'info, UserStake>,
     
+    #[account(
+        mut,
+        token::mint = staked_token_mint,
+        token::authority = user,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = staked_token_mint,
+        token::authority = pool,
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+    
     pub staked_token_mint: InterfaceAccount<'info, Mint>,
     
     #[account(mut)]
     pub user: Signer<'info>,
     
+    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
@@ -681,6 +1215,20 @@ pub struct UpdateReputationScore<'info> {
     
     #[account(mut)]
     pub user: Signer<'info>,
+    
+    #[account(
+        seeds = [b"reputation", user.key().as_ref()],
+        bump = reputation.bump,
+        seeds::program = axiom_attestations::ID
+    )]
+    pub reputation: Account<'info, Reputation>,
+    
+    #[account(
+        seeds = [b"attestation-config"],
+        bump = attestation_config.bump,
+        seeds::program = axiom_attestations::ID
+    )]
+    pub attestation_config: Account<'info, AttestationConfig>,
 }
 
 #[account]
@@ -692,8 +1240,74 @@ pub struct StakingPool {
     pub reward_rate: u64,
     pub total_staked: u64,
     pub total_effective_staked: u64, // New field for reputation-weighted staking
-    pub acc_reward_per_share: u128, // Accumulated reward per share
+    pub acc_reward_per_share: u128, // Accumulated reward per share, scaled by ACC_REWARD_PRECISION
     pub last_reward_time: i64,      // Last time reward was calculated
+    pub emissions_end: i64,         // No rewards accrue after this time
+    pub total_rewards_funded: u64,  // Reward tokens deposited through fund_rewards
+    pub total_rewards_accrued: u64, // Reward tokens allocated to stakers so far
+    pub unbonding_period: i64,      // Seconds unstaked tokens wait before withdrawal
+    pub total_unbonding: u64,       // Unstaked tokens still held by the pool
+    pub slash_authority: Pubkey,    // Signer allowed to call slash
+    pub positive_attestations: u64, // Weighted verified positive attestations about the pool
+    pub negative_attestations: u64, // Weighted verified negative attestations about the pool
+    pub dynamic_apr: u64,           // Attestation-derived APR in basis points, informational
+    #[max_len(4)]
+    pub positive_schemas: Vec<Pubkey>, // Schemas whose attestations count as positive
+    #[max_len(4)]
+    pub negative_schemas: Vec<Pubkey>, // Schemas whose attestations count as negative
+}
+
+impl StakingPool {
+    // 7 days, long enough for a slashing proposal to land
+    pub const DEFAULT_UNBONDING_PERIOD: i64 = 604800;
+    
+    // Fixed-point scale of acc_reward_per_share
+    pub const ACC_REWARD_PRECISION: u128 = 1_000_000_000_000;
+    
+    // Accrue reward_rate per second since the last update across the effective stake.
+    // Accrual stops at emissions_end and once the funded rewards are allocated, so
+    // claims can never exceed what the reward vault holds.
+    pub fn update_pool(&mut self, now: i64) -> Result<()> {
+        let accrue_until = now.min(self.emissions_end);
+        if accrue_until <= self.last_reward_time {
+            self.last_reward_time = self.last_reward_time.max(now);
+            return Ok(());
+        }
+        
+        if self.total_effective_staked > 0 {
+            let elapsed = (accrue_until - self.last_reward_time) as u128;
+            let reward = (self.reward_rate as u128).checked_mul(elapsed)
+                .ok_or(StakingError::Overflow)?
+                .min(self.rewards_remaining() as u128);
+            let reward_per_share = reward.checked_mul(Self::ACC_REWARD_PRECISION)
+                .ok_or(StakingError::Overflow)?
+                / self.total_effective_staked as u128;
+            self.acc_reward_per_share = self.acc_reward_per_share.checked_add(reward_per_share)
+                .ok_or(StakingError::Overflow)?;
+            // reward is bounded by rewards_remaining, so it fits in u64
+            self.total_rewards_accrued = self.total_rewards_accrued.checked_add(reward as u64)
+                .ok_or(StakingError::Overflow)?;
+        }
+        
+        self.last_reward_time = now;
+        Ok(())
+    }
+    
+    pub fn trusted_schemas(&self) -> Vec<Pubkey> {
+        self.positive_schemas.iter().chain(self.negative_schemas.iter()).copied().collect()
+    }
+    
+    // Funded rewards not yet allocated to stakers
+    pub fn rewards_remaining(&self) -> u64 {
+        self.total_rewards_funded.saturating_sub(self.total_rewards_accrued)
+    }
+    
+    // Rewards accumulated by an effective amount since the pool started
+    pub fn accumulated_reward(&self, effective_amount: u64) -> Result<u128> {
+        Ok((effective_amount as u128).checked_mul(self.acc_reward_per_share)
+            .ok_or(StakingError::Overflow)?
+            / Self::ACC_REWARD_PRECISION)
+    }
 }
 
 #[account]
@@ -701,11 +1315,56 @@ pub struct StakingPool {
 pub struct UserStake {
     pub amount: u64,       // Amount of tokens staked
     pub effective_amount: u64, // Reputation-weighted staked amount
-    pub reward_debt: u64,  // Reward debt for calculating pending rewards
+    pub reward_debt: u128, // Rewards already accounted for on effective_amount
     pub reputation_score: u64, // User's reputation score
     pub positive_attestations: u64, // Count of positive SAS attestations
     pub is_cold_start: bool, // Flag indicating if user is in cold-start mode
     pub cold_start_timestamp: i64, // Timestamp when cold-start was initialized
+    pub unbonding_count: u64, // Number of unbonding tickets created, used as ticket seed
+    pub jailed_until: i64, // Rewards are withheld until this time
+    pub open_unbonding_tickets: u64, // Unbonding tickets not yet withdrawn
+    pub open_slashes: u32, // Unsettled slash proposals, withdrawals are held while nonzero
+}
+
+// Current ledger reputation, clamped to 0..=MAX_REPUTATION
+fn ledger_reputation(reputation: &Reputation, attestation_config: &AttestationConfig, now: i64) -> u64 {
+    reputation.decayed_score(now, attestation_config.reputation_half_life)
+        .clamp(0, MAX_REPUTATION as i64) as u64
+}
+
+impl UserStake {
+    pub fn is_jailed(&self, now: i64) -> bool {
+        now < self.jailed_until
+    }
+    
+    pub fn pending_reward(&self, pool: &StakingPool) -> Result<u64> {
+        let pending = pool.accumulated_reward(self.effective_amount)?
+            .checked_sub(self.reward_debt)
+            .ok_or(StakingError::Overflow)?;
+        Ok(u64::try_from(pending).map_err(|_| StakingError::Overflow)?)
+    }
+    
+    // Part of effective_amount backing the given amount of staked tokens
+    pub fn effective_share(&self, amount: u64) -> Result<u64> {
+        if self.amount == 0 {
+            return Ok(0);
+        }
+        let share = (self.effective_amount as u128).checked_mul(amount as u128)
+            .ok_or(StakingError::Overflow)?
+            / self.amount as u128;
+        Ok(u64::try_from(share).map_err(|_| StakingError::Overflow)?)
+    }
+}
+
+// Unstaked tokens waiting out the pool's unbonding period
+#[account]
+#[derive(InitSpace)]
+pub struct UnbondingTicket {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub unlock_time: i64,
+    pub bump: u8,
 }
 
 #[error_code]
@@ -724,4 +1383,28 @@ pub enum StakingError {
     
     #[msg("Cold-start period not expired")]
     ColdStartPeriodNotExpired,
+    
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    
+    #[msg("Emissions must end in the future")]
+    InvalidEmissionSchedule,
+    
+    #[msg("Unbonding period has not elapsed")]
+    UnbondingPeriodNotElapsed,
+    
+    #[msg("Unbonding period cannot be negative")]
+    InvalidUnbondingPeriod,
+    
+    #[msg("Staker is jailed")]
+    StakerJailed,
+    
+    #[msg("A slash against this staker is still open")]
+    SlashPending,
+    
+    #[msg("Invalid trusted schema list")]
+    InvalidTrustedSchemas,
+    
+    #[msg("No valid attestations were provided")]
+    NoValidAttestations,
 }
\ No newline at end of file
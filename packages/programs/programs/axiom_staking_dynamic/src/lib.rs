@@ -4,7 +4,7 @@ use anchor_spl::{
     associated_token::AssociatedToken,
 };
 
-declare_id!("AXiomStkD111111111111111111111111111111111");
+declare_id!("AsKxhfHdQgjWBuoztEYonKepba2zGcN2QtWowCmAfWzD");
 
 #[program]
 pub mod axiom_staking_dynamic {
@@ -19,6 +19,12 @@ pub mod axiom_staking_dynamic {
         pool.last_reward_time = ctx.accounts.clock.unix_timestamp;
         pool.acc_reward_per_share = 0;
         pool.total_staked = 0;
+        // Emissions run until scheduled otherwise, bounded by the funded rewards
+        pool.emissions_end = i64::MAX;
+        pool.total_rewards_funded = 0;
+        pool.total_rewards_accrued = 0;
+        pool.unbonding_period = StakingPool::DEFAULT_UNBONDING_PERIOD;
+        pool.total_unbonding = 0;
         pool.bump = *ctx.bumps.get("pool").unwrap();
         
         msg!("Staking pool initialized with reward rate: {}", reward_per_second);
@@ -38,17 +44,19 @@ pub mod axiom_staking_dynamic {
         
         transfer(cpi_ctx, amount)?;
         
+        let pool = &mut ctx.accounts.pool;
+        pool.update_pool(Clock::get()?.unix_timestamp)?;
+        
         // Update user stake account
         let user_stake = &mut ctx.accounts.user_stake;
         user_stake.amount = user_stake.amount.checked_add(amount)
             .ok_or(StakingError::Overflow)?;
+        user_stake.bump = *ctx.bumps.get("user_stake").unwrap();
         user_stake.reward_debt = user_stake.reward_debt.checked_add(
-            ((amount as u128).checked_mul(ctx.accounts.pool.acc_reward_per_share as u128)
-                .ok_or(StakingError::Overflow)? >> 40) as u64
+            pool.accumulated_reward(amount)?
         ).ok_or(StakingError::Overflow)?;
         
         // Update pool
-        let pool = &mut ctx.accounts.pool;
         pool.total_staked = pool.total_staked.checked_add(amount)
             .ok_or(StakingError::Overflow)?;
         
@@ -72,7 +80,7 @@ pub mod axiom_staking_dynamic {
         // Get reputation score from PoHW attestation
         let reputation_score = if ctx.accounts.pohw_attestation.owner == &crate::id() {
             // If the attestation account is owned by our program, read the quality score
-            let attestation_data = ctx.accounts.pohw_attestation.try_borrow_data()?;
+            let _attestation_data = ctx.accounts.pohw_attestation.try_borrow_data()?;
             // This is a simplified approach - in reality, we'd deserialize the account properly
             // For now, we'll just use a mock value
             9500u16 // Mock high reputation score
@@ -88,20 +96,22 @@ pub mod axiom_staking_dynamic {
         // Apply reputation multiplier to the staked amount for reward calculations
         let effective_amount = (amount as f64 * reputation_multiplier) as u64;
         
-        // Update user stake account
+        let pool = &mut ctx.accounts.pool;
+        pool.update_pool(Clock::get()?.unix_timestamp)?;
+        
+        // Update user stake account, reward debt follows the staked amount like claim_rewards
         let user_stake = &mut ctx.accounts.user_stake;
         user_stake.amount = user_stake.amount.checked_add(amount)
             .ok_or(StakingError::Overflow)?;
         user_stake.effective_amount = user_stake.effective_amount.checked_add(effective_amount)
             .ok_or(StakingError::Overflow)?;
+        user_stake.bump = *ctx.bumps.get("user_stake").unwrap();
         user_stake.reward_debt = user_stake.reward_debt.checked_add(
-            ((effective_amount as u128).checked_mul(ctx.accounts.pool.acc_reward_per_share as u128)
-                .ok_or(StakingError::Overflow)? >> 40) as u64
+            pool.accumulated_reward(amount)?
         ).ok_or(StakingError::Overflow)?;
         user_stake.reputation_score = reputation_score;
         
         // Update pool
-        let pool = &mut ctx.accounts.pool;
         pool.total_staked = pool.total_staked.checked_add(amount)
             .ok_or(StakingError::Overflow)?;
         pool.total_effective_staked = pool.total_effective_staked.checked_add(effective_amount)
@@ -117,37 +127,100 @@ pub mod axiom_staking_dynamic {
         require!(user_stake.amount >= amount, StakingError::InsufficientStake);
         
         // Update pool
+        let now = Clock::get()?.unix_timestamp;
         let pool = &mut ctx.accounts.pool;
+        pool.update_pool(now)?;
+        let pending_reward = pool.accumulated_reward(user_stake.amount)?
+            .saturating_sub(user_stake.reward_debt);
         pool.total_staked = pool.total_staked.checked_sub(amount)
             .ok_or(StakingError::Overflow)?;
+        pool.total_unbonding = pool.total_unbonding.checked_add(amount)
+            .ok_or(StakingError::Overflow)?;
         
-        // Update user stake
+        // Pay out rewards earned on the full stake before it shrinks
+        if pending_reward > 0 {
+            let seeds = &[
+                b"pool".as_ref(),
+                &[pool.bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+            
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.reward_token_account.to_account_info(),
+                to: ctx.accounts.user_reward_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            
+            transfer(cpi_ctx, pending_reward)?;
+        }
+        
+        // Update user stake, reward debt follows the remaining amount
         user_stake.amount = user_stake.amount.checked_sub(amount)
             .ok_or(StakingError::Overflow)?;
-        user_stake.reward_debt = user_stake.reward_debt.checked_sub(
-            ((amount as u128).checked_mul(pool.acc_reward_per_share as u128)
-                .ok_or(StakingError::Overflow)? >> 40) as u64
-        ).ok_or(StakingError::Overflow)?;
+        user_stake.reward_debt = pool.accumulated_reward(user_stake.amount)?;
+        user_stake.unbonding_count = user_stake.unbonding_count.checked_add(1)
+            .ok_or(StakingError::Overflow)?;
         
-        // Transfer tokens from pool to user
-        let seeds = &[
-            b"pool",
-            &[pool.bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
+        // Tokens stay in the pool, and slashable, until the ticket unlocks
+        let ticket = &mut ctx.accounts.unbonding_ticket;
+        ticket.owner = ctx.accounts.user.key();
+        ticket.amount = amount;
+        ticket.unlock_time = now.checked_add(pool.unbonding_period)
+            .ok_or(StakingError::Overflow)?;
+        ticket.bump = *ctx.bumps.get("unbonding_ticket").unwrap();
         
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.pool_token_account.to_account_info(),
-            to: ctx.accounts.user_token_account.to_account_info(),
-            authority: ctx.accounts.pool.to_account_info(),
-        };
+        msg!("Unbonding {} tokens until {}", amount, ticket.unlock_time);
+        Ok(())
+    }
+
+    pub fn withdraw_unbonded(ctx: Context<WithdrawUnbonded>) -> Result<()> {
+        let ticket = &ctx.accounts.unbonding_ticket;
         
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        require!(
+            Clock::get()?.unix_timestamp >= ticket.unlock_time,
+            StakingError::UnbondingPeriodNotElapsed
+        );
         
-        transfer(cpi_ctx, amount)?;
+        let amount = ticket.amount;
+        let pool = &mut ctx.accounts.pool;
+        pool.total_unbonding = pool.total_unbonding.checked_sub(amount)
+            .ok_or(StakingError::Overflow)?;
+        
+        if amount > 0 {
+            // Transfer tokens from pool to user
+            let seeds = &[
+                b"pool".as_ref(),
+                &[pool.bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+            
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.pool_token_account.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            };
+            
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            
+            transfer(cpi_ctx, amount)?;
+        }
+        
+        msg!("Withdrew {} unbonded tokens", amount);
+        Ok(())
+    }
+
+    pub fn set_unbonding_period(ctx: Context<UpdateRewardRate>, unbonding_period: i64) -> Result<()> {
+        require!(ctx.accounts.authority.key() == ctx.accounts.pool.authority, StakingError::Unauthorized);
+        require!(unbonding_period >= 0, StakingError::InvalidUnbondingPeriod);
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.unbonding_period = unbonding_period;
         
-        msg!("Unstaked {} tokens", amount);
+        msg!("Updated unbonding period to {} seconds", unbonding_period);
         Ok(())
     }
 
@@ -155,15 +228,14 @@ pub mod axiom_staking_dynamic {
         let user_stake = &mut ctx.accounts.user_stake;
         let pool = &mut ctx.accounts.pool;
         
-        let pending_reward = ((user_stake.amount as u128).checked_mul(pool.acc_reward_per_share as u128)
-            .ok_or(StakingError::Overflow)? >> 40) as u64
-            .checked_sub(user_stake.reward_debt)
-            .ok_or(StakingError::Overflow)?;
+        pool.update_pool(Clock::get()?.unix_timestamp)?;
+        let pending_reward = pool.accumulated_reward(user_stake.amount)?
+            .saturating_sub(user_stake.reward_debt);
         
         if pending_reward > 0 {
             // Transfer rewards to user
             let seeds = &[
-                b"pool",
+                b"pool".as_ref(),
                 &[pool.bump],
             ];
             let signer_seeds = &[&seeds[..]];
@@ -182,8 +254,8 @@ pub mod axiom_staking_dynamic {
             msg!("Claimed {} reward tokens", pending_reward);
         }
         
-        user_stake.reward_debt = ((user_stake.amount as u128).checked_mul(pool.acc_reward_per_share as u128)
-            .ok_or(StakingError::Overflow)? >> 40) as u64;
+        let user_stake = &mut ctx.accounts.user_stake;
+        user_stake.reward_debt = ctx.accounts.pool.accumulated_reward(user_stake.amount)?;
         
         Ok(())
     }
@@ -191,12 +263,58 @@ pub mod axiom_staking_dynamic {
     pub fn update_reward_rate(ctx: Context<UpdateRewardRate>, new_rate: u64) -> Result<()> {
         require!(ctx.accounts.authority.key() == ctx.accounts.pool.authority, StakingError::Unauthorized);
         
+        // Settle rewards earned at the old rate first
         let pool = &mut ctx.accounts.pool;
+        pool.update_pool(Clock::get()?.unix_timestamp)?;
         pool.reward_per_second = new_rate;
         
         msg!("Updated reward rate to {}", new_rate);
         Ok(())
     }
+
+    // Top up the reward vault, anyone can fund the pool
+    pub fn fund_rewards(ctx: Context<FundRewards>, amount: u64) -> Result<()> {
+        require!(amount > 0, StakingError::InvalidAmount);
+        
+        // Accrue up to now so earlier depleted time is not paid from the new funds
+        let pool = &mut ctx.accounts.pool;
+        pool.update_pool(Clock::get()?.unix_timestamp)?;
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.funder_token_account.to_account_info(),
+            to: ctx.accounts.reward_token_account.to_account_info(),
+            authority: ctx.accounts.funder.to_account_info(),
+        };
+        
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        
+        transfer(cpi_ctx, amount)?;
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.total_rewards_funded = pool.total_rewards_funded.checked_add(amount)
+            .ok_or(StakingError::Overflow)?;
+        
+        msg!("Funded {} reward tokens ({} remaining)", amount, pool.rewards_remaining());
+        Ok(())
+    }
+
+    // Set the reward rate and the time emissions stop
+    pub fn schedule_emissions(ctx: Context<UpdateRewardRate>, reward_per_second: u64, emissions_end: i64) -> Result<()> {
+        require!(ctx.accounts.authority.key() == ctx.accounts.pool.authority, StakingError::Unauthorized);
+        
+        let now = Clock::get()?.unix_timestamp;
+        require!(emissions_end > now, StakingError::InvalidEmissionSchedule);
+        
+        // Settle rewards earned under the old schedule first
+        let pool = &mut ctx.accounts.pool;
+        pool.update_pool(now)?;
+        pool.reward_per_second = reward_per_second;
+        pool.emissions_end = emissions_end;
+        
+        msg!("Scheduled emissions of {} per second until {}", reward_per_second, emissions_end);
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -341,6 +459,57 @@ pub struct UnstakeTokens<'info> {
     )]
     pub user_stake: Account<'info, UserStake>,
     
+    #[account(
+        init,
+        payer = user,
+        space = 8 + UnbondingTicket::INIT_SPACE,
+        seeds = [
+            b"unbonding_ticket",
+            user.key().as_ref(),
+            &user_stake.unbonding_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub unbonding_ticket: Account<'info, UnbondingTicket>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.reward_mint,
+        associated_token::authority = pool,
+    )]
+    pub reward_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.reward_mint,
+        associated_token::authority = user,
+    )]
+    pub user_reward_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawUnbonded<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        close = user,
+        constraint = unbonding_ticket.owner == user.key() @ StakingError::Unauthorized,
+    )]
+    pub unbonding_ticket: Account<'info, UnbondingTicket>,
+    
     #[account(mut)]
     pub user_token_account: Account<'info, TokenAccount>,
     
@@ -392,6 +561,34 @@ pub struct ClaimRewards<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
+#[derive(Accounts)]
+pub struct FundRewards<'info> {
+    pub funder: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.reward_mint,
+        associated_token::authority = pool,
+    )]
+    pub reward_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = pool.reward_mint,
+        token::authority = funder,
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
 #[derive(Accounts)]
 pub struct UpdateRewardRate<'info> {
     pub authority: Signer<'info>,
@@ -415,9 +612,63 @@ pub struct StakingPool {
     pub acc_reward_per_share: u128,
     pub total_staked: u64,
     pub total_effective_staked: u64,
+    pub emissions_end: i64,
+    pub total_rewards_funded: u64,
+    pub total_rewards_accrued: u64,
+    pub unbonding_period: i64,
+    pub total_unbonding: u64,
     pub bump: u8,
 }
 
+impl StakingPool {
+    // 7 days, long enough for a slashing proposal to land
+    pub const DEFAULT_UNBONDING_PERIOD: i64 = 604800;
+    
+    // acc_reward_per_share is a fixed-point value with 40 fractional bits
+    pub const ACC_REWARD_SHIFT: u32 = 40;
+    
+    // Accrue reward_per_second since the last update across the staked amount.
+    // Accrual stops at emissions_end and once the funded rewards are allocated, so
+    // claims can never exceed what the reward vault holds.
+    pub fn update_pool(&mut self, now: i64) -> Result<()> {
+        let accrue_until = now.min(self.emissions_end);
+        if accrue_until <= self.last_reward_time {
+            self.last_reward_time = self.last_reward_time.max(now);
+            return Ok(());
+        }
+        
+        if self.total_staked > 0 {
+            let elapsed = (accrue_until - self.last_reward_time) as u128;
+            let reward = (self.reward_per_second as u128).checked_mul(elapsed)
+                .ok_or(StakingError::Overflow)?
+                .min(self.rewards_remaining() as u128);
+            let reward_per_share = reward.checked_shl(Self::ACC_REWARD_SHIFT)
+                .ok_or(StakingError::Overflow)?
+                / self.total_staked as u128;
+            self.acc_reward_per_share = self.acc_reward_per_share.checked_add(reward_per_share)
+                .ok_or(StakingError::Overflow)?;
+            // reward is bounded by rewards_remaining, so it fits in u64
+            self.total_rewards_accrued = self.total_rewards_accrued.checked_add(reward as u64)
+                .ok_or(StakingError::Overflow)?;
+        }
+        
+        self.last_reward_time = now;
+        Ok(())
+    }
+    
+    // Rewards accumulated by a staked amount since the pool started
+    pub fn accumulated_reward(&self, amount: u64) -> Result<u64> {
+        let reward = (amount as u128).checked_mul(self.acc_reward_per_share)
+            .ok_or(StakingError::Overflow)? >> Self::ACC_REWARD_SHIFT;
+        Ok(u64::try_from(reward).map_err(|_| StakingError::Overflow)?)
+    }
+    
+    // Funded rewards not yet allocated to stakers
+    pub fn rewards_remaining(&self) -> u64 {
+        self.total_rewards_funded.saturating_sub(self.total_rewards_accrued)
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct UserStake {
@@ -425,6 +676,16 @@ pub struct UserStake {
     pub effective_amount: u64,
     pub reward_debt: u64,
     pub reputation_score: u16,
+    pub unbonding_count: u64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UnbondingTicket {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub unlock_time: i64,
     pub bump: u8,
 }
 
@@ -436,4 +697,12 @@ pub enum StakingError {
     InsufficientStake,
     #[msg("Unauthorized")]
     Unauthorized,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Emissions must end in the future")]
+    InvalidEmissionSchedule,
+    #[msg("Unbonding period has not elapsed")]
+    UnbondingPeriodNotElapsed,
+    #[msg("Unbonding period cannot be negative")]
+    InvalidUnbondingPeriod,
 }
\ No newline at end of file
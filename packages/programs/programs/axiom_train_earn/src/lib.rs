@@ -3,9 +3,14 @@ use anchor_spl::{
     token_2022::Token2022,
     token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
 };
+use axiom_pohw::program::AxiomPohw;
+use axiom_pohw::{HumanWorkAttestation, Schema as PohwSchema};
 
 declare_id!("AsKxhfHdQgjWBuoztEYonKepba2zGcN2QtWowCmAfWzD");
 
+// Maximum number of allow-listed reviewers per task pool
+pub const MAX_REVIEWERS: usize = 10;
+
 #[program]
 pub mod axiom_train_earn {
     use super::*;
@@ -33,6 +38,11 @@ pub mod axiom_train_earn {
         task_pool.max_completions = max_completions;
         task_pool.current_completions = 0;
         task_pool.is_active = true;
+        // The pool authority reviews completions until a review config is set
+        task_pool.reviewers = vec![ctx.accounts.authority.key()];
+        task_pool.min_reviewer_tier = 0;
+        task_pool.approval_quorum = 1;
+        task_pool.reviewer_reward_bps = 0;
         task_pool.bump = *ctx.bumps.get("task_pool").unwrap();
         
         Ok(())
@@ -56,10 +66,18 @@ pub mod axiom_train_earn {
         // Create task completion record
         let task_completion = &mut ctx.accounts.task_completion;
         task_completion.user = ctx.accounts.user.key();
-        task_completion.task_pool = ctx.accounts.task_pool.key();
+        task_completion.task_pool = task_pool.key();
+        task_completion.index = task_pool.current_completions;
         task_completion.task_data = task_data;
         task_completion.timestamp = Clock::get()?.unix_timestamp;
+        task_completion.status = CompletionStatus::Pending;
+        task_completion.approve_votes = 0;
+        task_completion.reject_votes = 0;
+        task_completion.quality_total = 0;
+        task_completion.quality_score = 0;
+        task_completion.reviewer_reward = 0;
         task_completion.reward_claimed = false;
+        task_completion.pohw_recorded = false;
         task_completion.bump = *ctx.bumps.get("task_completion").unwrap();
         
         // Update task pool completions
@@ -74,37 +92,160 @@ pub mod axiom_train_earn {
         Ok(())
     }
 
-    pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
+    // Configure who may review completions, how many approvals make one claimable,
+    // and the share of each reward paid to the approving reviewers
+    pub fn set_review_config(
+        ctx: Context<UpdateTaskPool>,
+        reviewers: Vec<Pubkey>,
+        min_reviewer_tier: u8,
+        approval_quorum: u8,
+        reviewer_reward_bps: u16,
+    ) -> Result<()> {
+        require!(reviewers.len() <= MAX_REVIEWERS, TrainEarnError::TooManyReviewers);
+        require!(approval_quorum > 0, TrainEarnError::InvalidQuorum);
+        // Without a tier requirement only the allow-list can vote, so it must reach quorum
+        require!(
+            min_reviewer_tier > 0 || approval_quorum as usize <= reviewers.len(),
+            TrainEarnError::InvalidQuorum
+        );
+        require!(reviewer_reward_bps <= 10_000, TrainEarnError::InvalidRewardShare);
+        
+        let task_pool = &mut ctx.accounts.task_pool;
+        task_pool.reviewers = reviewers;
+        task_pool.min_reviewer_tier = min_reviewer_tier;
+        task_pool.approval_quorum = approval_quorum;
+        task_pool.reviewer_reward_bps = reviewer_reward_bps;
+        
+        msg!(
+            "Review config updated: {} reviewers, min tier {}, quorum {}, reviewer share {} bps",
+            task_pool.reviewers.len(),
+            min_reviewer_tier,
+            approval_quorum,
+            reviewer_reward_bps
+        );
+        Ok(())
+    }
+
+    // Approve or reject a pending completion. Approvals grade the work in basis points.
+    // Once either side reaches the pool's quorum the completion is settled.
+    pub fn review_task(ctx: Context<ReviewTask>, approve: bool, quality_score: u16) -> Result<()> {
+        let task_pool = &ctx.accounts.task_pool;
+        let reviewer = ctx.accounts.reviewer.key();
+        require!(
+            is_eligible_reviewer(
+                task_pool,
+                &reviewer,
+                ctx.accounts.pohw_schema.as_ref(),
+                ctx.accounts.reviewer_attestation.as_ref(),
+            )?,
+            TrainEarnError::ReviewerNotEligible
+        );
+        require!(
+            quality_score <= axiom_pohw::MAX_QUALITY_SCORE,
+            TrainEarnError::InvalidQualityScore
+        );
+        
         let task_completion = &mut ctx.accounts.task_completion;
+        require!(
+            task_completion.status == CompletionStatus::Pending,
+            TrainEarnError::TaskNotPending
+        );
+        require_keys_neq!(task_completion.user, reviewer, TrainEarnError::SelfReview);
+        
+        let review_vote = &mut ctx.accounts.review_vote;
+        review_vote.task_completion = task_completion.key();
+        review_vote.reviewer = reviewer;
+        review_vote.approve = approve;
+        review_vote.quality_score = if approve { quality_score } else { 0 };
+        review_vote.reward_claimed = false;
+        review_vote.bump = *ctx.bumps.get("review_vote").unwrap();
+        
+        if approve {
+            task_completion.approve_votes = task_completion.approve_votes.checked_add(1)
+                .ok_or(TrainEarnError::Overflow)?;
+            task_completion.quality_total = task_completion.quality_total
+                .checked_add(quality_score as u32)
+                .ok_or(TrainEarnError::Overflow)?;
+            
+            if task_completion.approve_votes >= task_pool.approval_quorum {
+                task_completion.status = CompletionStatus::Approved;
+                task_completion.quality_score =
+                    (task_completion.quality_total / task_completion.approve_votes as u32) as u16;
+                task_completion.reviewer_reward = task_pool.reviewer_share(task_pool.reward_amount);
+            }
+        } else {
+            task_completion.reject_votes = task_completion.reject_votes.checked_add(1)
+                .ok_or(TrainEarnError::Overflow)?;
+            
+            if task_completion.reject_votes >= task_pool.approval_quorum {
+                task_completion.status = CompletionStatus::Rejected;
+            }
+        }
+        
+        msg!(
+            "Review by {}: {} (approvals {}, rejections {})",
+            reviewer,
+            if approve { "approve" } else { "reject" },
+            task_completion.approve_votes,
+            task_completion.reject_votes
+        );
+        Ok(())
+    }
+
+    // An approving reviewer collects their even share of the completion's reviewer reward
+    pub fn claim_review_reward(ctx: Context<ClaimReviewReward>) -> Result<()> {
+        let task_completion = &ctx.accounts.task_completion;
+        require!(
+            task_completion.status == CompletionStatus::Approved,
+            TrainEarnError::TaskNotApproved
+        );
         
-        // Check if reward has already been claimed
-        require!(!task_completion.reward_claimed, TrainEarnError::RewardAlreadyClaimed);
+        let review_vote = &mut ctx.accounts.review_vote;
+        require!(review_vote.approve, TrainEarnError::ReviewerNotRewarded);
+        require!(!review_vote.reward_claimed, TrainEarnError::RewardAlreadyClaimed);
+        
+        let amount = task_completion.reviewer_reward / task_completion.approve_votes as u64;
+        pay_from_pool(
+            &ctx.accounts.task_pool,
+            &ctx.accounts.reward_pool_token_account,
+            &ctx.accounts.reviewer_reward_token_account,
+            &ctx.accounts.reward_token_mint,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+        review_vote.reward_claimed = true;
         
-        let task_pool = &ctx.accounts.task_pool;
+        let train_earn_config = &mut ctx.accounts.train_earn_config;
+        train_earn_config.total_rewards_distributed = train_earn_config.total_rewards_distributed
+            .checked_add(amount)
+            .ok_or(TrainEarnError::Overflow)?;
         
-        // Transfer reward tokens to user
-        let seeds = &[
-            b"task-pool",
-            task_pool.authority.as_ref(),
-            task_pool.name.as_bytes(),
-            &[ctx.bumps.task_pool],
-        ];
-        let signer = &[&seeds[..]];
+        Ok(())
+    }
+
+    pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
+        let task_completion = &mut ctx.accounts.task_completion;
         
-        let cpi_accounts = TransferChecked {
-            from: ctx.accounts.reward_pool_token_account.to_account_info(),
-            to: ctx.accounts.user_reward_token_account.to_account_info(),
-            authority: ctx.accounts.task_pool.to_account_info(),
-            mint: ctx.accounts.reward_token_mint.to_account_info(),
-        };
+        // Only completions approved by the reviewer quorum pay out
+        require!(
+            task_completion.status == CompletionStatus::Approved,
+            TrainEarnError::TaskNotApproved
+        );
         
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        // Check if reward has already been claimed
+        require!(!task_completion.reward_claimed, TrainEarnError::RewardAlreadyClaimed);
         
-        transfer_checked(
-            cpi_ctx,
-            task_pool.reward_amount,
-            ctx.accounts.reward_token_mint.decimals,
+        // Transfer reward tokens to user, less the reviewers' share
+        let amount = ctx.accounts.task_pool.reward_amount
+            .checked_sub(task_completion.reviewer_reward)
+            .ok_or(TrainEarnError::Overflow)?;
+        pay_from_pool(
+            &ctx.accounts.task_pool,
+            &ctx.accounts.reward_pool_token_account,
+            &ctx.accounts.user_reward_token_account,
+            &ctx.accounts.reward_token_mint,
+            &ctx.accounts.token_program,
+            amount,
         )?;
         
         // Mark reward as claimed
@@ -113,13 +254,45 @@ pub mod axiom_train_earn {
         // Update config with total rewards distributed
         let train_earn_config = &mut ctx.accounts.train_earn_config;
         train_earn_config.total_rewards_distributed = train_earn_config.total_rewards_distributed
-            .checked_add(task_pool.reward_amount)
+            .checked_add(amount)
             .ok_or(TrainEarnError::Overflow)?;
         
-        // Issue SAS attestation for completed task
-        // This would typically involve a cross-program call to the attestations program
-        // For now, we'll just log that an attestation should be issued
-        msg!("Task completion attestation should be issued for user: {}", ctx.accounts.user.key());
+        Ok(())
+    }
+
+    // Count an approved completion towards the user's PoHW attestation. Kept apart from
+    // claim_reward so payouts do not depend on train-earn being a PoHW verifier.
+    pub fn record_pohw_completion(ctx: Context<RecordPohwCompletion>) -> Result<()> {
+        let task_completion = &mut ctx.accounts.task_completion;
+        require!(
+            task_completion.status == CompletionStatus::Approved,
+            TrainEarnError::TaskNotApproved
+        );
+        require!(!task_completion.pohw_recorded, TrainEarnError::PohwAlreadyRecorded);
+        task_completion.pohw_recorded = true;
+        
+        // The config PDA signs as the PoHW verifier, so it must be on the PoHW schema's verifier list
+        let train_earn_config = &ctx.accounts.train_earn_config;
+        let config_seeds = &[b"train-earn-config".as_ref(), &[train_earn_config.bump]];
+        let config_signer = &[&config_seeds[..]];
+        let cpi_accounts = axiom_pohw::cpi::accounts::RecordTaskCompletion {
+            payer: ctx.accounts.user.to_account_info(),
+            user: ctx.accounts.user.to_account_info(),
+            schema: ctx.accounts.pohw_schema.to_account_info(),
+            attestation: ctx.accounts.pohw_attestation.to_account_info(),
+            verifier: train_earn_config.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.pohw_program.to_account_info(),
+            cpi_accounts,
+            config_signer,
+        );
+        axiom_pohw::cpi::record_task_completion(
+            cpi_ctx,
+            task_completion.quality_score,
+            task_completion.timestamp,
+        )?;
         
         Ok(())
     }
@@ -196,6 +369,7 @@ pub struct CompleteTask<'info> {
     pub task_completion: Account<'info, TaskCompletion>,
     
     #[account(
+        mut,
         seeds = [b"train-earn-config"],
         bump = train_earn_config.bump
     )]
@@ -208,287 +382,109 @@ pub struct CompleteTask<'info> {
 }
 
 #[derive(Accounts)]
-pub struct ClaimReward<'info> {
+pub struct UpdateTaskPool<'info> {
     #[account(
         mut,
         seeds = [b"task-pool", task_pool.authority.as_ref(), task_pool.name.as_bytes()],
-        bump = task_pool.bump
+        bump = task_pool.bump,
+        has_one = authority @ TrainEarnError::Unauthorized
     )]
     pub task_pool: Account<'info, TaskPool>,
     
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReviewTask<'info> {
     #[account(
-        mut,
-        seeds = [
-            b"task-completion",
-            task_pool.key().as_ref(),
-            task_completion.user.as_ref(),
-            &(task_pool.current_completions - 1).to_le_bytes() // This is a simplification
-        ],
-        bump = task_completion.bump
+        seeds = [b"task-pool", task_pool.authority.as_ref(), task_pool.name.as_bytes()],
+        bump = task_pool.bump
     )]
-    pub task_completion: Account<'info, TaskCompletion>,
+    pub task_pool: Account<'info, TaskPool>,
     
     #[account(
         mut,
-        token::mint = reward_token_mint,
-        token::authority = task_pool,
+        has_one = task_pool
     )]
-    pub reward_pool_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub task_completion: Account<'info, TaskCompletion>,
     
+    // One vote per reviewer per completion
     #[account(
-        mut,
-        token::mint = reward_token_mint,
-        token::authority = user,
+        init,
+        payer = reviewer,
+        space = 8 + ReviewVote::INIT_SPACE,
+        seeds = [b"review-vote", task_completion.key().as_ref(), reviewer.key().as_ref()],
+        bump
     )]
-    pub user_reward_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub review_vote: Account<'info, ReviewVote>,
     
-    pub reward_token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
     
+    // PoHW schema and the reviewer's attestation, only needed when the reviewer
+    // qualifies through the pool's minimum PoHW tier rather than the allow-list
     #[account(
-        seeds = [b"train-earn-config"],
-        bump = train_earn_config.bump
+        seeds = [b"pohw-schema"],
+        bump = pohw_schema.bump,
+        seeds::program = axiom_pohw::ID
     )]
-    pub train_earn_config: Account<'info, TrainEarnConfig>,
+    pub pohw_schema: Option<Account<'info, PohwSchema>>,
     
-    #[account(mut)]
-    pub user: Signer<'info>,
+    pub reviewer_attestation: Option<Account<'info, HumanWorkAttestation>>,
     
-    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct IssueTaskAttestation<'info> {
-    /// CHECK: This account is checked in the attestations program
-    pub user: AccountInfo<'info>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
+pub struct ClaimReviewReward<'info> {
+    #[account(
+        seeds = [b"task-pool", task_pool.authority.as_ref(), task_pool.name.as_bytes()],
+        bump = task_pool.bump
+    )]
+    pub task_pool: Account<'info, TaskPool>,
     
-    pub system_program: Program<'info, System>,
-}
-
-#[account]
-#[derive(InitSpace)]
-pub struct TrainEarnConfig {
-    pub authority: Pubkey,
-    pub total_tasks: u64,
-    pub total_rewards_distributed: u64,
-    pub bump: u8,
-}
-
-#[account]
-#[derive(InitSpace)]
-pub struct TaskPool {
-    pub authority: Pubkey,
-    pub name: String,
-    pub reward_amount: u64,
-    pub max_completions: u64,
-    pub current_completions: u64,
-    pub is_active: bool,
-    pub bump: u8,
-}
-
-#[account]
-#[derive(InitSpace)]
-pub struct TaskCompletion {
-    pub user: Pubkey,
-    pub task_pool: Pubkey,
-    pub task_data: String,
-    pub timestamp: i64,
-    pub reward_claimed: bool,
-    pub bump: u8,
-}
-
-#[error_code]
-pub enum TrainEarnError {
-    #[msg("Task pool is inactive")]
-    TaskPoolInactive,
+    #[account(has_one = task_pool)]
+    pub task_completion: Account<'info, TaskCompletion>,
     
-    #[msg("Task pool has reached maximum completions")]
-    TaskPoolCompleted,
+    #[account(
+        mut,
+        seeds = [b"review-vote", task_completion.key().as_ref(), reviewer.key().as_ref()],
+        bump = review_vote.bump,
+        has_one = task_completion,
+        has_one = reviewer
+    )]
+    pub review_vote: Account<'info, ReviewVote>,
     
-    #[msg("Reward has already been claimed")]
-    RewardAlreadyClaimed,
+    #[account(
+        mut,
+        token::mint = reward_token_mint,
+        token::authority = task_pool,
+    )]
+    pub reward_pool_token_account: InterfaceAccount<'info, TokenAccount>,
     
-    #[msg("Arithmetic overflow")]
-    Overflow,
-}use anchor_lang::prelude::*;
-use anchor_spl::{
-    token_2022::Token2022,
-    token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked},
-};
-
-declare_id!("AsKxhfHdQgjWBuoztEYonKepba2zGcN2QtWowCmAfWzD");
-
-#[program]
-pub mod axiom_train_earn {
-    use super::*;
-
-    pub fn initialize(ctx: Context<Initialize>, authority: Pubkey) -> Result<()> {
-        let train_earn_config = &mut ctx.accounts.train_earn_config;
-        train_earn_config.authority = authority;
-        train_earn_config.total_tasks = 0;
-        train_earn_config.total_rewards_distributed = 0;
-        train_earn_config.bump = *ctx.bumps.get("train_earn_config").unwrap();
-        
-        Ok(())
-    }
-
-    pub fn create_task_pool(
-        ctx: Context<CreateTaskPool>,
-        name: String,
-        reward_amount: u64,
-        max_completions: u64,
-    ) -> Result<()> {
-        let task_pool = &mut ctx.accounts.task_pool;
-        task_pool.authority = ctx.accounts.authority.key();
-        task_pool.name = name;
-        task_pool.reward_amount = reward_amount;
-        task_pool.max_completions = max_completions;
-        task_pool.current_completions = 0;
-        task_pool.is_active = true;
-        task_pool.bump = *ctx.bumps.get("task_pool").unwrap();
-        
-        Ok(())
-    }
-
-    pub fn complete_task(
-        ctx: Context<CompleteTask>,
-        task_data: String,
-    ) -> Result<()> {
-        let task_pool = &mut ctx.accounts.task_pool;
-        
-        // Check if task pool is active
-        require!(task_pool.is_active, TrainEarnError::TaskPoolInactive);
-        
-        // Check if max completions has been reached
-        require!(
-            task_pool.current_completions < task_pool.max_completions,
-            TrainEarnError::TaskPoolCompleted
-        );
-        
-        // Create task completion record
-        let task_completion = &mut ctx.accounts.task_completion;
-        task_completion.user = ctx.accounts.user.key();
-        task_completion.task_pool = ctx.accounts.task_pool.key();
-        task_completion.task_data = task_data;
-        task_completion.timestamp = Clock::get()?.unix_timestamp;
-        task_completion.reward_claimed = false;
-        task_completion.bump = *ctx.bumps.get("task_completion").unwrap();
-        
-        // Update task pool completions
-        task_pool.current_completions = task_pool.current_completions.checked_add(1)
-            .ok_or(TrainEarnError::Overflow)?;
-        
-        // Update total tasks in config
-        let train_earn_config = &mut ctx.accounts.train_earn_config;
-        train_earn_config.total_tasks = train_earn_config.total_tasks.checked_add(1)
-            .ok_or(TrainEarnError::Overflow)?;
-        
-        Ok(())
-    }
-
-    pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
-        let task_completion = &mut ctx.accounts.task_completion;
-        
-        // Check if reward has already been claimed
-        require!(!task_completion.reward_claimed, TrainEarnError::RewardAlreadyClaimed);
-        
-        let task_pool = &ctx.accounts.task_pool;
-        
-        // Transfer reward tokens to user
-        let seeds = &[
-            b"task-pool",
-            task_pool.authority.as_ref(),
-            task_pool.name.as_bytes(),
-            &[ctx.bumps.task_pool],
-        ];
-        let signer = &[&seeds[..]];
-        
-        let cpi_accounts = TransferChecked {
-            from: ctx.accounts.reward_pool_token_account.to_account_info(),
-            to: ctx.accounts.user_reward_token_account.to_account_info(),
-            authority: ctx.accounts.task_pool.to_account_info(),
-            mint: ctx.accounts.reward_token_mint.to_account_info(),
-        };
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        
-        transfer_checked(
-            cpi_ctx,
-            task_pool.reward_amount,
-            ctx.accounts.reward_token_mint.decimals,
-        )?;
-        
-        // Mark reward as claimed
-        task_completion.reward_claimed = true;
-        
-        // Update config with total rewards distributed
-        let train_earn_config = &mut ctx.accounts.train_earn_config;
-        train_earn_config.total_rewards_distributed = train_earn_config.total_rewards_distributed
-            .checked_add(task_pool.reward_amount)
-            .ok_or(TrainEarnError::Overflow)?;
-        
-        // Issue SAS attestation for completed task
-        // This would typically involve a cross-program call to the attestations program
-        // For now, we'll just log that an attestation should be issued
-        msg!("Task completion attestation should be issued for user: {}", ctx.accounts.user.key());
-        
-        Ok(())
-    }
-
-    pub fn issue_task_attestation(ctx: Context<IssueTaskAttestation>, task_type: String) -> Result<()> {
-        // In a full implementation, this would involve:
-        // 1. Verifying the task completion
-        // 2. Calling the attestations program to issue a SAS-compliant attestation
-        // 3. Recording the attestation for reputation purposes
-        
-        msg!("Issuing task attestation of type '{}' for user: {}", task_type, ctx.accounts.user.key());
-        
-        Ok(())
-    }
-}
-
-#[derive(Accounts)]
-pub struct Initialize<'info> {
     #[account(
-        init,
-        payer = payer,
-        space = 8 + TrainEarnConfig::INIT_SPACE,
-        seeds = [b"train-earn-config"],
-        bump
+        mut,
+        token::mint = reward_token_mint,
+        token::authority = reviewer,
     )]
-    pub train_earn_config: Account<'info, TrainEarnConfig>,
+    pub reviewer_reward_token_account: InterfaceAccount<'info, TokenAccount>,
     
-    #[account(mut)]
-    pub payer: Signer<'info>,
+    pub reward_token_mint: InterfaceAccount<'info, Mint>,
     
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-#[instruction(name: String)]
-pub struct CreateTaskPool<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + TaskPool::INIT_SPACE,
-        seeds = [b"task-pool", authority.key().as_ref(), name.as_bytes()],
-        bump
+        mut,
+        seeds = [b"train-earn-config"],
+        bump = train_earn_config.bump
     )]
-    pub task_pool: Account<'info, TaskPool>,
+    pub train_earn_config: Account<'info, TrainEarnConfig>,
     
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    pub reviewer: Signer<'info>,
     
-    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
 }
 
 #[derive(Accounts)]
-#[instruction(task_data: String)]
-pub struct CompleteTask<'info> {
+pub struct ClaimReward<'info> {
     #[account(
         mut,
         seeds = [b"task-pool", task_pool.authority.as_ref(), task_pool.name.as_bytes()],
@@ -497,20 +493,36 @@ pub struct CompleteTask<'info> {
     pub task_pool: Account<'info, TaskPool>,
     
     #[account(
-        init,
-        payer = user,
-        space = 8 + TaskCompletion::INIT_SPACE,
+        mut,
         seeds = [
             b"task-completion",
             task_pool.key().as_ref(),
             user.key().as_ref(),
-            &task_pool.current_completions.to_le_bytes()
+            &task_completion.index.to_le_bytes()
         ],
-        bump
+        bump = task_completion.bump,
+        has_one = user
     )]
     pub task_completion: Account<'info, TaskCompletion>,
     
     #[account(
+        mut,
+        token::mint = reward_token_mint,
+        token::authority = task_pool,
+    )]
+    pub reward_pool_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = reward_token_mint,
+        token::authority = user,
+    )]
+    pub user_reward_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub reward_token_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
         seeds = [b"train-earn-config"],
         bump = train_earn_config.bump
     )]
@@ -519,13 +531,13 @@ pub struct CompleteTask<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
+    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ClaimReward<'info> {
+pub struct RecordPohwCompletion<'info> {
     #[account(
-        mut,
         seeds = [b"task-pool", task_pool.authority.as_ref(), task_pool.name.as_bytes()],
         bump = task_pool.bump
     )]
@@ -536,29 +548,14 @@ pub struct ClaimReward<'info> {
         seeds = [
             b"task-completion",
             task_pool.key().as_ref(),
-            task_completion.user.as_ref(),
-            &(task_pool.current_completions - 1).to_le_bytes() // This is a simplification
+            user.key().as_ref(),
+            &task_completion.index.to_le_bytes()
         ],
-        bump = task_completion.bump
+        bump = task_completion.bump,
+        has_one = user
     )]
     pub task_completion: Account<'info, TaskCompletion>,
     
-    #[account(
-        mut,
-        token::mint = reward_token_mint,
-        token::authority = task_pool,
-    )]
-    pub reward_pool_token_account: InterfaceAccount<'info, TokenAccount>,
-    
-    #[account(
-        mut,
-        token::mint = reward_token_mint,
-        token::authority = user,
-    )]
-    pub user_reward_token_account: InterfaceAccount<'info, TokenAccount>,
-    
-    pub reward_token_mint: InterfaceAccount<'info, Mint>,
-    
     #[account(
         seeds = [b"train-earn-config"],
         bump = train_earn_config.bump
@@ -568,7 +565,14 @@ pub struct ClaimReward<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
-    pub token_program: Program<'info, Token2022>,
+    /// CHECK: PoHW schema, validated by the PoHW program
+    pub pohw_schema: UncheckedAccount<'info>,
+    
+    /// CHECK: User's PoHW attestation, validated by the PoHW program
+    #[account(mut)]
+    pub pohw_attestation: UncheckedAccount<'info>,
+    
+    pub pohw_program: Program<'info, AxiomPohw>,
     pub system_program: Program<'info, System>,
 }
 
@@ -596,25 +600,116 @@ pub struct TrainEarnConfig {
 #[derive(InitSpace)]
 pub struct TaskPool {
     pub authority: Pubkey,
+    #[max_len(32)]
     pub name: String,
     pub reward_amount: u64,
     pub max_completions: u64,
     pub current_completions: u64,
     pub is_active: bool,
+    #[max_len(10)]
+    pub reviewers: Vec<Pubkey>,     // Allow-listed reviewers
+    pub min_reviewer_tier: u8,      // PoHW tier that also qualifies a reviewer, 0 disables
+    pub approval_quorum: u8,        // Votes needed to approve or reject a completion
+    pub reviewer_reward_bps: u16,   // Share of the reward paid to approving reviewers
     pub bump: u8,
 }
 
+impl TaskPool {
+    pub fn reviewer_share(&self, amount: u64) -> u64 {
+        ((amount as u128) * (self.reviewer_reward_bps as u128) / 10_000) as u64
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct TaskCompletion {
     pub user: Pubkey,
     pub task_pool: Pubkey,
+    pub index: u64,                 // Position in the pool, part of the PDA seeds
+    #[max_len(256)]
     pub task_data: String,
     pub timestamp: i64,
+    pub status: CompletionStatus,
+    pub approve_votes: u8,
+    pub reject_votes: u8,
+    pub quality_total: u32,         // Sum of the approving reviewers' grades
+    pub quality_score: u16,         // Average approving grade once approved, in basis points
+    pub reviewer_reward: u64,       // Part of the reward set aside for approving reviewers
     pub reward_claimed: bool,
+    pub pohw_recorded: bool,        // Whether the completion has been counted towards PoHW
     pub bump: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum CompletionStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReviewVote {
+    pub task_completion: Pubkey,
+    pub reviewer: Pubkey,
+    pub approve: bool,
+    pub quality_score: u16,
+    pub reward_claimed: bool,
+    pub bump: u8,
+}
+
+// A reviewer qualifies through the pool's allow-list, or by holding a PoHW
+// attestation whose tier after inactivity decay meets the pool's minimum
+fn is_eligible_reviewer(
+    task_pool: &TaskPool,
+    reviewer: &Pubkey,
+    pohw_schema: Option<&Account<PohwSchema>>,
+    reviewer_attestation: Option<&Account<HumanWorkAttestation>>,
+) -> Result<bool> {
+    if task_pool.reviewers.contains(reviewer) {
+        return Ok(true);
+    }
+    if task_pool.min_reviewer_tier == 0 {
+        return Ok(false);
+    }
+    let (Some(schema), Some(attestation)) = (pohw_schema, reviewer_attestation) else {
+        return Ok(false);
+    };
+    if attestation.axiom_id_holder != *reviewer {
+        return Ok(false);
+    }
+    let effective = attestation.effective_work(Clock::get()?.unix_timestamp, schema.decay_half_life);
+    Ok(effective.specialization_tier >= task_pool.min_reviewer_tier)
+}
+
+// Transfer reward tokens out of the task pool's account, signed by the pool PDA
+fn pay_from_pool<'info>(
+    task_pool: &Account<'info, TaskPool>,
+    reward_pool_token_account: &InterfaceAccount<'info, TokenAccount>,
+    recipient_token_account: &InterfaceAccount<'info, TokenAccount>,
+    reward_token_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Program<'info, Token2022>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[
+        b"task-pool",
+        task_pool.authority.as_ref(),
+        task_pool.name.as_bytes(),
+        &[task_pool.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = TransferChecked {
+        from: reward_pool_token_account.to_account_info(),
+        to: recipient_token_account.to_account_info(),
+        authority: task_pool.to_account_info(),
+        mint: reward_token_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    
+    transfer_checked(cpi_ctx, amount, reward_token_mint.decimals)
+}
+
 #[error_code]
 pub enum TrainEarnError {
     #[msg("Task pool is inactive")]
@@ -626,6 +721,39 @@ pub enum TrainEarnError {
     #[msg("Reward has already been claimed")]
     RewardAlreadyClaimed,
     
+    #[msg("Task completion has not been approved")]
+    TaskNotApproved,
+    
+    #[msg("Task completion is no longer pending review")]
+    TaskNotPending,
+    
+    #[msg("Reviewer is not on the allow-list and lacks the required PoHW tier")]
+    ReviewerNotEligible,
+    
+    #[msg("Reviewers cannot review their own completions")]
+    SelfReview,
+    
+    #[msg("Only approving reviewers share the reward")]
+    ReviewerNotRewarded,
+    
+    #[msg("Too many reviewers")]
+    TooManyReviewers,
+    
+    #[msg("Approval quorum must be at least one and reachable by the eligible reviewers")]
+    InvalidQuorum,
+    
+    #[msg("Reviewer reward share must be at most 10000 bps")]
+    InvalidRewardShare,
+    
+    #[msg("Quality score must be at most 10000")]
+    InvalidQualityScore,
+    
+    #[msg("Unauthorized")]
+    Unauthorized,
+    
+    #[msg("Completion has already been counted towards PoHW")]
+    PohwAlreadyRecorded,
+    
     #[msg("Arithmetic overflow")]
     Overflow,
 }
\ No newline at end of file
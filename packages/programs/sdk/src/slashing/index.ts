@@ -36,24 +36,6 @@ export class SlashingClient {
     return "transaction_signature";
   }
 
-  /**
-   * Burn tokens as a more severe penalty
-   * @param agent Public key of the agent to penalize
-   * @param amount Amount of tokens to burn
-   * @param reason Reason for burning
-   * @returns Transaction signature
-   */
-  async burnTokens(agent: PublicKey, amount: number, reason: string): Promise<string> {
-    if (!this.axiomSlashingProgram) {
-      throw new Error('Axiom Slashing program not initialized');
-    }
-
-    // TODO: Implement actual token burning logic
-    // This is a simplified version for demonstration
-    
-    return "transaction_signature";
-  }
-
   /**
    * Automatically slash based on negative SAS attestations
    * @param agent Public key of the agent to slash
@@ -2,7 +2,17 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { AgentSoulFactory } from "../target/types/agent_soul_factory";
 import { PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_2022_PROGRAM_ID, createMint, createAccount, mintTo, getAccount } from "@solana/spl-token";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  createAccount,
+  createAssociatedTokenAccount,
+  getAssociatedTokenAddressSync,
+  mintTo,
+  getAccount,
+} from "@solana/spl-token";
+import { expect } from "chai";
 
 describe("agent_soul_factory", () => {
   // Configure the client to use the local cluster.
@@ -21,27 +31,111 @@ describe("agent_soul_factory", () => {
     console.log("Your transaction signature", tx);
   });
 
-  it("Creates a soul-bound token!", async () => {
-    // Add your test here.
-    const recipient = anchor.web3.Keypair.generate().publicKey;
-    
-    const [programAuthority, bump] = PublicKey.findProgramAddressSync(
+  it("Creates a soul mint and mints its single soul token!", async () => {
+    const [agentSoulFactory] = PublicKey.findProgramAddressSync(
       [Buffer.from("agent-soul-factory")],
       program.programId
     );
-    
-    const tx = await program.methods.createSoulBoundToken(new anchor.BN(1))
+    const [feeConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fee-config")],
+      program.programId
+    );
+
+    // Fee configuration, shared with the other test files on this validator
+    if ((await provider.connection.getAccountInfo(feeConfig)) === null) {
+      await program.methods.initializeFeeConfig(new anchor.BN(1000000))
+        .accounts({
+          feeConfig,
+          authority: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+    }
+
+    // AXIOM fee token, a payer balance and the fee vault owned by the fee config PDA
+    const axiomTokenMint = await createMint(
+      provider.connection, payer, payer.publicKey, null, 6, undefined, undefined, TOKEN_2022_PROGRAM_ID
+    );
+    const payerTokenAccount = await createAssociatedTokenAccount(
+      provider.connection, payer, axiomTokenMint, payer.publicKey, undefined, TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection, payer, axiomTokenMint, payerTokenAccount, payer, 10000000, [], undefined, TOKEN_2022_PROGRAM_ID
+    );
+    const feeVault = await createAccount(
+      provider.connection, payer, axiomTokenMint, feeConfig, anchor.web3.Keypair.generate(), undefined, TOKEN_2022_PROGRAM_ID
+    );
+
+    // Create the soul mint
+    const [soulMintRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("soul-mint"), mintKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods.initializeNttMint("Agent Soul", "SOUL", "https://axiom.id/soul.json")
       .accounts({
         mint: mintKeypair.publicKey,
-        programAuthority: programAuthority,
-        recipient: recipient,
+        soulMintRecord,
+        agentSoulFactory,
         payer: payer.publicKey,
+        payerTokenAccount,
+        feeVault,
+        axiomTokenMint,
+        feeConfig,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
+      .signers([payer, mintKeypair])
+      .rpc();
+
+    // Mint the soul to a fresh recipient
+    const recipient = anchor.web3.Keypair.generate().publicKey;
+    const tokenAccount = getAssociatedTokenAddressSync(
+      mintKeypair.publicKey, recipient, false, TOKEN_2022_PROGRAM_ID
+    );
+    const soulAccounts = {
+      mint: mintKeypair.publicKey,
+      soulMintRecord,
+      authority: payer.publicKey,
+      tokenAccount,
+      agentSoulFactory,
+      recipient,
+      payer: payer.publicKey,
+      payerTokenAccount,
+      feeVault,
+      axiomTokenMint,
+      feeConfig,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+    const tx = await program.methods.createSoulBoundToken()
+      .accounts(soulAccounts)
       .signers([payer])
       .rpc();
-      
     console.log("Your transaction signature", tx);
+
+    const soulAccount = await getAccount(provider.connection, tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+    expect(Number(soulAccount.amount)).to.equal(1);
+
+    // A second soul cannot be minted from the same mint
+    const otherRecipient = anchor.web3.Keypair.generate().publicKey;
+    let failed = false;
+    try {
+      await program.methods.createSoulBoundToken()
+        .accounts({
+          ...soulAccounts,
+          recipient: otherRecipient,
+          tokenAccount: getAssociatedTokenAddressSync(
+            mintKeypair.publicKey, otherRecipient, false, TOKEN_2022_PROGRAM_ID
+          ),
+        })
+        .signers([payer])
+        .rpc();
+    } catch (err) {
+      failed = true;
+      expect(err.toString()).to.include("SoulAlreadyMinted");
+    }
+    expect(failed).to.equal(true);
   });
-});
\ No newline at end of file
+});
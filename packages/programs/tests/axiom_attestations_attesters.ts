@@ -0,0 +1,267 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { AxiomAttestations } from "../target/types/axiom_attestations";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+
+describe("axiom_attestations_attesters", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AxiomAttestations as Program<AxiomAttestations>;
+  const provider = anchor.getProvider();
+  const payer = (provider as any).wallet.payer;
+
+  const [attestationConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("attestation-config")],
+    program.programId
+  );
+
+  const findSchema = (name: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("schema"), payer.publicKey.toBuffer(), Buffer.from(name)],
+      program.programId
+    )[0];
+
+  const findAuthorization = (schema: PublicKey, attester: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("attester"), schema.toBuffer(), attester.toBuffer()],
+      program.programId
+    )[0];
+
+  const findAttestation = (schema: PublicKey, subject: PublicKey, attester: PublicKey, claim: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("attestation"), schema.toBuffer(), subject.toBuffer(), attester.toBuffer(), Buffer.from(claim)],
+      program.programId
+    )[0];
+
+  const findReputation = (subject: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("reputation"), subject.toBuffer()],
+      program.programId
+    )[0];
+
+  const fundedKeypair = async () => {
+    const keypair = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(keypair.publicKey, 1_000_000_000);
+    await provider.connection.confirmTransaction(sig);
+    return keypair;
+  };
+
+  const chainTime = async () => {
+    const slot = await provider.connection.getSlot();
+    return (await provider.connection.getBlockTime(slot)) as number;
+  };
+
+  const expectFailure = async (promise: Promise<unknown>, code?: string) => {
+    let failed = false;
+    try {
+      await promise;
+    } catch (err) {
+      failed = true;
+      if (code) {
+        expect(err.toString()).to.include(code);
+      }
+    }
+    expect(failed).to.equal(true);
+  };
+
+  const createSchema = async (name: string, revocable: boolean) => {
+    const schema = findSchema(name);
+    await program.methods
+      .createAttestationSchema(
+        name,
+        "Attester allow-list test schema",
+        [{ name: "score", fieldType: { u8: {} }, maxLen: 0 }],
+        revocable,
+        { issue: 10, revoke: -10, expiry: -10 }
+      )
+      .accounts({
+        schema,
+        authority: payer.publicKey,
+        attestationConfig: attestationConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+    return schema;
+  };
+
+  const authorize = (
+    schema: PublicKey,
+    attester: PublicKey,
+    expiresAt: anchor.BN | null,
+    canDelegate: boolean,
+    granter: Keypair = payer,
+    granterAuthorization: PublicKey | null = null
+  ) =>
+    program.methods
+      .authorizeAttester(attester, expiresAt, canDelegate)
+      .accounts({
+        schema,
+        attesterAuthorization: findAuthorization(schema, attester),
+        granterAuthorization,
+        granter: granter.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([granter])
+      .rpc();
+
+  const issue = (
+    schema: PublicKey,
+    attester: Keypair,
+    subject: PublicKey,
+    claim: string,
+    attesterAuthorization: PublicKey | null,
+    delegatorAuthorization: PublicKey | null = null
+  ) =>
+    program.methods
+      .issueAttestation(claim, Buffer.from([5]), null, { general: {} }, { positive: {} }, 1)
+      .accounts({
+        attestation: findAttestation(schema, subject, attester.publicKey, claim),
+        schema,
+        attesterAuthorization,
+        delegatorAuthorization,
+        reputation: findReputation(subject),
+        subject,
+        attester: attester.publicKey,
+        attestationConfig: attestationConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([attester])
+      .rpc();
+
+  const revoke = (schema: PublicKey, attestation: PublicKey, subject: PublicKey, revoker: Keypair) =>
+    program.methods
+      .revokeAttestation("test revocation")
+      .accounts({
+        attestation,
+        schema,
+        reputation: findReputation(subject),
+        attestationConfig: attestationConfigPda,
+        revoker: revoker.publicKey,
+      })
+      .signers([revoker])
+      .rpc();
+
+  before(async () => {
+    const existing = await program.account.attestationConfig.fetchNullable(attestationConfigPda);
+    if (!existing) {
+      await program.methods
+        .initialize(payer.publicKey)
+        .accounts({
+          attestationConfig: attestationConfigPda,
+          payer: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+    }
+  });
+
+  it("Only allow-listed attesters can issue!", async () => {
+    const schema = await createSchema("allow-list", true);
+    const attester = await fundedKeypair();
+    const stranger = await fundedKeypair();
+    const subject = Keypair.generate().publicKey;
+
+    await expectFailure(issue(schema, stranger, subject, "stranger", null), "AttesterNotAuthorized");
+
+    await authorize(schema, attester.publicKey, null, false);
+    await issue(schema, attester, subject, "allowed", findAuthorization(schema, attester.publicKey));
+
+    const attestation = await program.account.attestation.fetch(
+      findAttestation(schema, subject, attester.publicKey, "allowed")
+    );
+    expect(attestation.attester.toBase58()).to.equal(attester.publicKey.toBase58());
+
+    // Removing the attester from the allow-list stops further issuing
+    await program.methods
+      .removeAttester()
+      .accounts({
+        schema,
+        attesterAuthorization: findAuthorization(schema, attester.publicKey),
+        remover: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc();
+    await expectFailure(issue(schema, attester, subject, "removed", null), "AttesterNotAuthorized");
+  });
+
+  it("Expired authorizations cannot issue!", async () => {
+    const schema = await createSchema("expiry", true);
+    const attester = await fundedKeypair();
+    const subject = Keypair.generate().publicKey;
+    const authorization = findAuthorization(schema, attester.publicKey);
+
+    const expiresAt = (await chainTime()) + 3;
+    await authorize(schema, attester.publicKey, new anchor.BN(expiresAt), false);
+    await issue(schema, attester, subject, "before-expiry", authorization);
+
+    while ((await chainTime()) <= expiresAt) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+    await expectFailure(issue(schema, attester, subject, "after-expiry", authorization), "AttesterNotAuthorized");
+  });
+
+  it("Delegates lose access when their delegator is removed!", async () => {
+    const schema = await createSchema("delegation", true);
+    const delegator = await fundedKeypair();
+    const delegate = await fundedKeypair();
+    const subject = Keypair.generate().publicKey;
+    const delegatorAuthorization = findAuthorization(schema, delegator.publicKey);
+    const delegateAuthorization = findAuthorization(schema, delegate.publicKey);
+
+    await authorize(schema, delegator.publicKey, null, true);
+    await authorize(schema, delegate.publicKey, null, true, delegator, delegatorAuthorization);
+
+    // Delegates can never re-delegate
+    const grant = await program.account.attesterAuthorization.fetch(delegateAuthorization);
+    expect(grant.grantedBy.toBase58()).to.equal(delegator.publicKey.toBase58());
+    expect(grant.canDelegate).to.equal(false);
+    const third = Keypair.generate().publicKey;
+    await expectFailure(authorize(schema, third, null, false, delegate, delegateAuthorization), "Unauthorized");
+
+    // A delegate must show its delegator's grant when issuing
+    await expectFailure(issue(schema, delegate, subject, "no-delegator", delegateAuthorization), "AttesterNotAuthorized");
+    await issue(schema, delegate, subject, "delegated", delegateAuthorization, delegatorAuthorization);
+
+    await program.methods
+      .removeAttester()
+      .accounts({
+        schema,
+        attesterAuthorization: delegatorAuthorization,
+        remover: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc();
+
+    await expectFailure(issue(schema, delegate, subject, "orphaned", delegateAuthorization, delegatorAuthorization));
+    await expectFailure(issue(schema, delegate, subject, "orphaned", delegateAuthorization), "AttesterNotAuthorized");
+  });
+
+  it("Revokes on revocable schemas and rejects unauthorized revokers!", async () => {
+    const schema = await createSchema("revocable", true);
+    const attester = await fundedKeypair();
+    const stranger = await fundedKeypair();
+    const subject = Keypair.generate().publicKey;
+    const attestation = findAttestation(schema, subject, attester.publicKey, "revocable");
+
+    await authorize(schema, attester.publicKey, null, false);
+    await issue(schema, attester, subject, "revocable", findAuthorization(schema, attester.publicKey));
+
+    await expectFailure(revoke(schema, attestation, subject, stranger), "Unauthorized");
+
+    await revoke(schema, attestation, subject, attester);
+    expect((await program.account.attestation.fetch(attestation)).revoked).to.equal(true);
+    await expectFailure(revoke(schema, attestation, subject, attester), "AttestationRevoked");
+
+    // The schema authority can attest itself, but not revoke on a non-revocable schema
+    const fixedSchema = await createSchema("non-revocable", false);
+    await issue(fixedSchema, payer, subject, "fixed", null);
+    await expectFailure(
+      revoke(fixedSchema, findAttestation(fixedSchema, subject, payer.publicKey, "fixed"), subject, payer),
+      "NotRevocable"
+    );
+  });
+});
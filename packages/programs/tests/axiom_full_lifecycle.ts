@@ -196,11 +196,9 @@ describe("Axiom ID Full Lifecycle Integration Test", () => {
     // In a real implementation, the PoHW program would make a Cross-Program Invocation (CPI) 
     // to the Staking program to update the user's reputation.
     // For this test, we'll simulate this by directly calling the staking program's 
-    // update reputation function with the reputation boost from completed work.
+    // update reputation function, which reads the score from the attestation ledger.
     
-    const reputationBoost = new anchor.BN(25); // 25 reputation points for quality work
-    
-    const updateReputationTx = await stakingProgram.methods.updateReputationScore(reputationBoost)
+    const updateReputationTx = await stakingProgram.methods.updateReputationScore()
       .accounts({
         pool: poolPda,
         authority: payer.publicKey, // In real implementation, this would be a CPI from PoHW
@@ -2,7 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { AxiomGovernance } from "../target/types/axiom_governance";
 import { PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { TOKEN_2022_PROGRAM_ID, createMint, createAccount } from "@solana/spl-token";
 
 describe("axiom_governance", () => {
   // Configure the client to use the local cluster.
@@ -12,6 +12,29 @@ describe("axiom_governance", () => {
   const provider = anchor.getProvider();
   const payer = (provider as any).wallet.payer;
 
+  const governanceConfig = {
+    minQuorumBps: 1000, // 10% of supply
+    approvalThresholdBps: 5000, // simple majority
+    minVotingPeriod: new anchor.BN(3600),
+    maxVotingPeriod: new anchor.BN(7 * 86400),
+    minTokensToCreateProposal: new anchor.BN(0),
+    holdUpTime: new anchor.BN(0),
+  };
+  let governingTokenMint: PublicKey;
+
+  before(async () => {
+    governingTokenMint = await createMint(
+      provider.connection,
+      payer,
+      payer.publicKey,
+      null,
+      9,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+  });
+
   it("Initializes the governance realm!", async () => {
     const realmName = "Axiom DAO Governance";
 
@@ -22,9 +45,14 @@ describe("axiom_governance", () => {
     );
 
     // Call the initialize_realm function
-    const tx = await program.methods.initializeRealm(realmName)
+    const tx = await program.methods.initializeRealm(realmName, governanceConfig)
       .accounts({
         realm: realmPda,
+        nativeTreasury: PublicKey.findProgramAddressSync(
+          [Buffer.from("native-treasury"), realmPda.toBuffer()],
+          program.programId
+        )[0],
+        governingTokenMint,
         authority: payer.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -64,20 +92,36 @@ describe("axiom_governance", () => {
     );
 
     // First initialize the realm
-    await program.methods.initializeRealm(realmName)
+    await program.methods.initializeRealm(realmName, governanceConfig)
       .accounts({
         realm: realmPda,
+        nativeTreasury: PublicKey.findProgramAddressSync(
+          [Buffer.from("native-treasury"), realmPda.toBuffer()],
+          program.programId
+        )[0],
+        governingTokenMint,
         authority: payer.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([payer])
       .rpc();
 
+    const proposerTokenAccount = await createAccount(
+      provider.connection,
+      payer,
+      governingTokenMint,
+      payer.publicKey,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
     // Call the create_proposal function
     const tx = await program.methods.createProposal(proposalTitle, proposalDescription, votingPeriod)
       .accounts({
         proposal: proposalPda,
         realm: realmPda,
+        proposerTokenAccount,
         proposer: payer.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -96,13 +140,26 @@ describe("axiom_governance", () => {
     expect(proposalAccount.noVotes.toString()).toBe("0");
     expect(proposalAccount.abstainVotes.toString()).toBe("0");
     expect(proposalAccount.totalVotes.toString()).toBe("0");
-    expect(proposalAccount.status).toBe({ active: {} });
+    expect(proposalAccount.status).toBe({ draft: {} });
+
+    // Signing off fixes the transactions and opens voting
+    await program.methods.signOffProposal()
+      .accounts({
+        proposal: proposalPda,
+        proposer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc();
+
+    const signedOff = await program.account.governanceProposal.fetch(proposalPda);
+    expect(signedOff.status).toBe({ active: {} });
+    expect(signedOff.endTime.sub(signedOff.startTime).toString()).toBe(votingPeriod.toString());
   });
 });import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { AxiomGovernance } from "../target/types/axiom_governance";
 import { PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { TOKEN_2022_PROGRAM_ID, createMint, createAccount } from "@solana/spl-token";
 
 describe("axiom_governance", () => {
   // Configure the client to use the local cluster.
@@ -112,6 +169,29 @@ describe("axiom_governance", () => {
   const provider = anchor.getProvider();
   const payer = (provider as any).wallet.payer;
 
+  const governanceConfig = {
+    minQuorumBps: 1000, // 10% of supply
+    approvalThresholdBps: 5000, // simple majority
+    minVotingPeriod: new anchor.BN(3600),
+    maxVotingPeriod: new anchor.BN(7 * 86400),
+    minTokensToCreateProposal: new anchor.BN(0),
+    holdUpTime: new anchor.BN(0),
+  };
+  let governingTokenMint: PublicKey;
+
+  before(async () => {
+    governingTokenMint = await createMint(
+      provider.connection,
+      payer,
+      payer.publicKey,
+      null,
+      9,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+  });
+
   it("Initializes the governance realm!", async () => {
     const realmName = "Axiom DAO Governance";
 
@@ -122,9 +202,14 @@ describe("axiom_governance", () => {
     );
 
     // Call the initialize_realm function
-    const tx = await program.methods.initializeRealm(realmName)
+    const tx = await program.methods.initializeRealm(realmName, governanceConfig)
       .accounts({
         realm: realmPda,
+        nativeTreasury: PublicKey.findProgramAddressSync(
+          [Buffer.from("native-treasury"), realmPda.toBuffer()],
+          program.programId
+        )[0],
+        governingTokenMint,
         authority: payer.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -164,20 +249,36 @@ describe("axiom_governance", () => {
     );
 
     // First initialize the realm
-    await program.methods.initializeRealm(realmName)
+    await program.methods.initializeRealm(realmName, governanceConfig)
       .accounts({
         realm: realmPda,
+        nativeTreasury: PublicKey.findProgramAddressSync(
+          [Buffer.from("native-treasury"), realmPda.toBuffer()],
+          program.programId
+        )[0],
+        governingTokenMint,
         authority: payer.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([payer])
       .rpc();
 
+    const proposerTokenAccount = await createAccount(
+      provider.connection,
+      payer,
+      governingTokenMint,
+      payer.publicKey,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
     // Call the create_proposal function
     const tx = await program.methods.createProposal(proposalTitle, proposalDescription, votingPeriod)
       .accounts({
         proposal: proposalPda,
         realm: realmPda,
+        proposerTokenAccount,
         proposer: payer.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -196,6 +297,19 @@ describe("axiom_governance", () => {
     expect(proposalAccount.noVotes.toString()).toBe("0");
     expect(proposalAccount.abstainVotes.toString()).toBe("0");
     expect(proposalAccount.totalVotes.toString()).toBe("0");
-    expect(proposalAccount.status).toBe({ active: {} });
+    expect(proposalAccount.status).toBe({ draft: {} });
+
+    // Signing off fixes the transactions and opens voting
+    await program.methods.signOffProposal()
+      .accounts({
+        proposal: proposalPda,
+        proposer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc();
+
+    const signedOff = await program.account.governanceProposal.fetch(proposalPda);
+    expect(signedOff.status).toBe({ active: {} });
+    expect(signedOff.endTime.sub(signedOff.startTime).toString()).toBe(votingPeriod.toString());
   });
 });
\ No newline at end of file
@@ -1,6 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { AxiomId } from "../target/types/axiom_id";
+import { AxiomAttestations } from "../target/types/axiom_attestations";
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 
 describe("axiom_id_comprehensive", () => {
@@ -8,6 +9,7 @@ describe("axiom_id_comprehensive", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
 
   const program = anchor.workspace.AxiomId as Program<AxiomId>;
+  const attestationsProgram = anchor.workspace.AxiomAttestations as Program<AxiomAttestations>;
   const provider = anchor.getProvider();
   const payer = (provider as any).wallet.payer;
 
@@ -113,31 +115,43 @@ describe("axiom_id_comprehensive", () => {
     console.log("Identity created successfully for update test");
   });
 
-  // Test 5: Authority can update reputation
-  it("Allows authority to update reputation!", async () => {
+  // Test 5: Reputation can only come from the attestation ledger
+  it("Rejects reputation sync without a ledger account!", async () => {
     // Use the existing identity from previous tests
     const [identityPda, bump] = PublicKey.findProgramAddressSync(
       [Buffer.from("axiom-identity"), payer.publicKey.toBuffer()],
       program.programId
     );
+    const [reputationPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("reputation"), payer.publicKey.toBuffer()],
+      attestationsProgram.programId
+    );
+    const [attestationConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("attestation-config")],
+      attestationsProgram.programId
+    );
     
-    // Update reputation by +10
-    const reputationChange = new anchor.BN(10);
-    
-    const tx = await program.methods
-      .updateReputation(reputationChange)
-      .accounts({
-        identityAccount: identityPda,
-        authority: payer.publicKey,
-      })
-      .signers([payer])
-      .rpc();
-    
-    console.log("Update reputation transaction signature", tx);
+    // No attestations have been issued about the payer, so there is no ledger to sync from
+    try {
+      await program.methods
+        .syncReputation()
+        .accounts({
+          identityAccount: identityPda,
+          reputation: reputationPda,
+          attestationConfig: attestationConfigPda,
+        })
+        .rpc();
+      
+      // If we reach here, the test should fail
+      expect(true).toBe(false); // This should not be reached
+    } catch (error) {
+      console.log("Reputation sync correctly required a ledger account");
+      expect(error).toBeDefined();
+    }
     
-    // Verify the reputation was updated
+    // The cached reputation is untouched
     const identityAccount = await program.account.axiomAiIdentity.fetch(identityPda);
-    expect(identityAccount.reputation.toString()).toBe("10");
+    expect(identityAccount.reputation.toString()).toBe("0");
   });
 
   // Test 6: Authority can stake tokens
@@ -175,17 +189,24 @@ describe("axiom_id_comprehensive", () => {
       program.programId
     );
     
-    // Attempt to update reputation - this should fail
+    // Attempt to sync the payer's identity from the stranger's ledger - this should fail
     try {
-      const reputationChange = new anchor.BN(5);
+      const [strangerReputationPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reputation"), stranger.publicKey.toBuffer()],
+        attestationsProgram.programId
+      );
+      const [attestationConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("attestation-config")],
+        attestationsProgram.programId
+      );
       
       await program.methods
-        .updateReputation(reputationChange)
+        .syncReputation()
         .accounts({
           identityAccount: identityPda,
-          authority: stranger.publicKey, // Stranger trying to update
+          reputation: strangerReputationPda,
+          attestationConfig: attestationConfigPda,
         })
-        .signers([stranger])
         .rpc();
       
       // If we reach here, the test should fail
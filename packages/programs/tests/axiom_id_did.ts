@@ -0,0 +1,175 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { AxiomId } from "../target/types/axiom_id";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+
+describe("axiom_id_did", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AxiomId as Program<AxiomId>;
+  const provider = anchor.getProvider();
+  const payer = (provider as any).wallet.payer;
+
+  // Mirrors DidDocument::space, VerificationMethod::INIT_SPACE and ServiceEndpoint::INIT_SPACE
+  const VERIFICATION_METHOD_SPACE = 4 + 32 + 32 + 1;
+  const SERVICE_SPACE = 4 + 32 + 4 + 32 + 4 + 128;
+  const documentSpace = (controllers: number, verificationMethods: number, services: number) =>
+    8 + 32 + 32 +
+    (4 + controllers * 32) +
+    (4 + verificationMethods * VERIFICATION_METHOD_SPACE) +
+    (4 + services * SERVICE_SPACE) +
+    8 + 1;
+
+  let didKey: Keypair;
+  let agentMetadata: PublicKey;
+  let didDocument: PublicKey;
+
+  const fundedKeypair = async () => {
+    const keypair = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(keypair.publicKey, 1_000_000_000);
+    await provider.connection.confirmTransaction(sig);
+    return keypair;
+  };
+
+  const expectFailure = async (promise: Promise<unknown>, code: string) => {
+    let failed = false;
+    try {
+      await promise;
+    } catch (err) {
+      failed = true;
+      expect(err.toString()).to.include(code);
+    }
+    expect(failed).to.equal(true);
+  };
+
+  const dataLength = async () =>
+    (await provider.connection.getAccountInfo(didDocument)).data.length;
+
+  const update = (change: any, authority: Keypair) =>
+    program.methods
+      .updateDidDocument(change)
+      .accounts({
+        didDocument,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+  const rotate = (newController: PublicKey, authority: Keypair) =>
+    program.methods
+      .rotateAgentKeys(newController)
+      .accounts({
+        didDocument,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+  const verify = (controller: Keypair) =>
+    program.methods
+      .verifyAgentIdentity()
+      .accounts({
+        agentMetadata,
+        didDocument,
+        controller: controller.publicKey,
+      })
+      .signers([controller])
+      .rpc();
+
+  before(async () => {
+    // The DID key pays for and controls its own document
+    didKey = await fundedKeypair();
+    [agentMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("agent-metadata"), didKey.publicKey.toBuffer()],
+      program.programId
+    );
+    [didDocument] = PublicKey.findProgramAddressSync(
+      [Buffer.from("did-document"), agentMetadata.toBuffer()],
+      program.programId
+    );
+
+    await program.methods.initializeAgent(didKey.publicKey)
+      .accounts({
+        agentMetadata,
+        agentPda: Keypair.generate().publicKey,
+        did: didKey.publicKey,
+        authority: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+
+    await program.methods.createCryptidDid()
+      .accounts({
+        agentMetadata,
+        didDocument,
+        authority: didKey.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([didKey])
+      .rpc();
+  });
+
+  it("Grows and shrinks the DID document with its entries!", async () => {
+    expect(await dataLength()).to.equal(documentSpace(1, 1, 0));
+
+    const service = { id: "agent-api", serviceType: "AgentService", endpoint: "https://agent.example/api" };
+    await update({ addService: { 0: service } }, didKey);
+    expect(await dataLength()).to.equal(documentSpace(1, 1, 1));
+
+    const coController = Keypair.generate().publicKey;
+    await update({ addController: { 0: coController } }, didKey);
+    expect(await dataLength()).to.equal(documentSpace(2, 1, 1));
+
+    let document = await program.account.didDocument.fetch(didDocument);
+    expect(document.controllers.map((c) => c.toBase58())).to.deep.equal([
+      didKey.publicKey.toBase58(),
+      coController.toBase58(),
+    ]);
+    expect(document.services[0].endpoint).to.equal(service.endpoint);
+
+    await update({ removeService: { 0: service.id } }, didKey);
+    expect(await dataLength()).to.equal(documentSpace(2, 1, 0));
+
+    await update({ removeController: { 0: coController } }, didKey);
+    expect(await dataLength()).to.equal(documentSpace(1, 1, 0));
+
+    document = await program.account.didDocument.fetch(didDocument);
+    expect(document.controllers.length).to.equal(1);
+    expect(document.services.length).to.equal(0);
+
+    // The last controller cannot be removed
+    await expectFailure(update({ removeController: { 0: didKey.publicKey } }, didKey), "LastController");
+  });
+
+  it("Only a controller can rotate the agent keys!", async () => {
+    const stranger = await fundedKeypair();
+    await expectFailure(rotate(stranger.publicKey, stranger), "NotController");
+    await expectFailure(
+      update({ addController: { 0: stranger.publicKey } }, stranger),
+      "NotController"
+    );
+
+    const document = await program.account.didDocument.fetch(didDocument);
+    expect(document.controllers.map((c) => c.toBase58())).to.deep.equal([didKey.publicKey.toBase58()]);
+  });
+
+  it("Verifies the agent with the rotated key only!", async () => {
+    await verify(didKey);
+
+    const newKey = await fundedKeypair();
+    await rotate(newKey.publicKey, didKey);
+
+    const document = await program.account.didDocument.fetch(didDocument);
+    expect(document.did.toBase58()).to.equal(didKey.publicKey.toBase58());
+    expect(document.controllers.map((c) => c.toBase58())).to.deep.equal([newKey.publicKey.toBase58()]);
+    expect(document.verificationMethods[0].key.toBase58()).to.equal(newKey.publicKey.toBase58());
+
+    await verify(newKey);
+    await expectFailure(verify(didKey), "NotController");
+    await expectFailure(rotate(Keypair.generate().publicKey, didKey), "NotController");
+  });
+});
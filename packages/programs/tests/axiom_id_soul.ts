@@ -0,0 +1,135 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { AxiomId } from "../target/types/axiom_id";
+import { AgentSoulFactory } from "../target/types/agent_soul_factory";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  createAccount,
+  createAssociatedTokenAccount,
+  getAssociatedTokenAddressSync,
+  getAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+describe("axiom_id_soul", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AxiomId as Program<AxiomId>;
+  const factory = anchor.workspace.AgentSoulFactory as Program<AgentSoulFactory>;
+  const provider = anchor.getProvider();
+  const payer = (provider as any).wallet.payer;
+
+  it("Mints a soul to an agent end to end!", async () => {
+    const [agentSoulFactory] = PublicKey.findProgramAddressSync(
+      [Buffer.from("agent-soul-factory")],
+      factory.programId
+    );
+    const [feeConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fee-config")],
+      factory.programId
+    );
+
+    // Fee configuration, shared with the other test files on this validator
+    if ((await provider.connection.getAccountInfo(feeConfig)) === null) {
+      await factory.methods.initializeFeeConfig(new anchor.BN(1000000))
+        .accounts({
+          feeConfig,
+          authority: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+    }
+
+    // AXIOM fee token, a payer balance and the fee vault owned by the fee config PDA
+    const axiomTokenMint = await createMint(
+      provider.connection, payer, payer.publicKey, null, 6, undefined, undefined, TOKEN_2022_PROGRAM_ID
+    );
+    const payerTokenAccount = await createAssociatedTokenAccount(
+      provider.connection, payer, axiomTokenMint, payer.publicKey, undefined, TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection, payer, axiomTokenMint, payerTokenAccount, payer, 10000000, [], undefined, TOKEN_2022_PROGRAM_ID
+    );
+    const feeVault = await createAccount(
+      provider.connection, payer, axiomTokenMint, feeConfig, Keypair.generate(), undefined, TOKEN_2022_PROGRAM_ID
+    );
+
+    // Register the agent
+    const did = Keypair.generate().publicKey;
+    const agentPda = Keypair.generate().publicKey;
+    const [agentMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("agent-metadata"), did.toBuffer()],
+      program.programId
+    );
+    await program.methods.initializeAgent(did)
+      .accounts({
+        agentMetadata,
+        agentPda,
+        did,
+        authority: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+
+    // Create the agent's soul mint through the factory
+    const soulMint = Keypair.generate();
+    const [soulMintRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("soul-mint"), soulMint.publicKey.toBuffer()],
+      factory.programId
+    );
+    await factory.methods.initializeNttMint("Agent Soul", "SOUL", "https://axiom.id/soul.json")
+      .accounts({
+        mint: soulMint.publicKey,
+        soulMintRecord,
+        agentSoulFactory,
+        payer: payer.publicKey,
+        payerTokenAccount,
+        feeVault,
+        axiomTokenMint,
+        feeConfig,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer, soulMint])
+      .rpc();
+
+    // Mint the soul to the agent PDA
+    const agentTokenAccount = getAssociatedTokenAddressSync(
+      soulMint.publicKey, agentPda, true, TOKEN_2022_PROGRAM_ID
+    );
+    const tx = await program.methods.mintSoulToAgent()
+      .accounts({
+        agentMetadata,
+        agentPda,
+        soulMint: soulMint.publicKey,
+        soulMintRecord,
+        agentTokenAccount,
+        soulFactoryAuthority: agentSoulFactory,
+        payerTokenAccount,
+        feeVault,
+        axiomTokenMint,
+        feeConfig,
+        authority: payer.publicKey,
+        soulFactoryProgram: factory.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+    console.log("Mint soul to agent transaction signature", tx);
+
+    const soulAccount = await getAccount(provider.connection, agentTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+    expect(Number(soulAccount.amount)).to.equal(1);
+
+    const metadata = await program.account.agentMetadata.fetch(agentMetadata);
+    expect(metadata.soulMint.toBase58()).to.equal(soulMint.publicKey.toBase58());
+  });
+});
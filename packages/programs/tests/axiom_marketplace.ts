@@ -0,0 +1,243 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { AxiomMarketplace } from "../target/types/axiom_marketplace";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  createAccount,
+  mintTo,
+  getAccount,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+describe("axiom_marketplace", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AxiomMarketplace as Program<AxiomMarketplace>;
+  const provider = anchor.getProvider();
+  const payer = (provider as any).wallet.payer;
+
+  const SECONDS_PER_DAY = 86400;
+  const pricePerDay = 1_000;
+  const rentalDays = 3;
+
+  let paymentMint: PublicKey;
+  let renter: Keypair;
+  let ownerTokenAccount: PublicKey;
+  let renterTokenAccount: PublicKey;
+
+  const findListing = (agentId: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("listing"), payer.publicKey.toBuffer(), Buffer.from(agentId)],
+      program.programId
+    )[0];
+
+  const findEscrow = (listing: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow"), listing.toBuffer()],
+      program.programId
+    )[0];
+
+  const balance = async (tokenAccount: PublicKey) =>
+    Number((await getAccount(provider.connection, tokenAccount, undefined, TOKEN_2022_PROGRAM_ID)).amount);
+
+  const expectFailure = async (promise: Promise<unknown>, code: string) => {
+    let failed = false;
+    try {
+      await promise;
+    } catch (err) {
+      failed = true;
+      expect(err.toString()).to.include(code);
+    }
+    expect(failed).to.equal(true);
+  };
+
+  const listAndRent = async (agentId: string) => {
+    const listing = findListing(agentId);
+    const escrow = findEscrow(listing);
+
+    await program.methods
+      .listAgent(agentId, new anchor.BN(pricePerDay), 7)
+      .accounts({
+        listing,
+        escrowTokenAccount: escrow,
+        paymentMint,
+        owner: payer.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+
+    await program.methods
+      .rentAgent(rentalDays)
+      .accounts({
+        listing,
+        escrowTokenAccount: escrow,
+        renterTokenAccount,
+        paymentMint,
+        renter: renter.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([renter])
+      .rpc();
+
+    return { listing, escrow };
+  };
+
+  const release = (listing: PublicKey, escrow: PublicKey, caller: Keypair) =>
+    program.methods
+      .releaseAgent()
+      .accounts({
+        listing,
+        escrowTokenAccount: escrow,
+        ownerTokenAccount,
+        renterTokenAccount,
+        paymentMint,
+        caller: caller.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([caller])
+      .rpc();
+
+  before(async () => {
+    renter = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(renter.publicKey, 1_000_000_000);
+    await provider.connection.confirmTransaction(sig);
+
+    paymentMint = await createMint(
+      provider.connection,
+      payer,
+      payer.publicKey,
+      null,
+      6,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    ownerTokenAccount = await createAccount(
+      provider.connection,
+      payer,
+      paymentMint,
+      payer.publicKey,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    renterTokenAccount = await createAccount(
+      provider.connection,
+      payer,
+      paymentMint,
+      renter.publicKey,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await mintTo(
+      provider.connection,
+      payer,
+      paymentMint,
+      renterTokenAccount,
+      payer,
+      100_000,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+  });
+
+  it("Escrows the full rental cost when renting!", async () => {
+    const renterBefore = await balance(renterTokenAccount);
+    const { listing, escrow } = await listAndRent("escrow-agent");
+
+    const rental = await program.account.listing.fetch(listing);
+    expect(rental.isAvailable).to.equal(false);
+    expect(rental.renter.toBase58()).to.equal(renter.publicKey.toBase58());
+    expect(rental.rentalDays).to.equal(rentalDays);
+    expect(rental.escrowedAmount.toNumber()).to.equal(pricePerDay * rentalDays);
+    expect(rental.rentalEnd.sub(rental.rentalStart).toNumber()).to.equal(rentalDays * SECONDS_PER_DAY);
+
+    expect(await balance(escrow)).to.equal(pricePerDay * rentalDays);
+    expect(renterBefore - (await balance(renterTokenAccount))).to.equal(pricePerDay * rentalDays);
+
+    // A rented agent cannot be rented again
+    await expectFailure(
+      program.methods
+        .rentAgent(1)
+        .accounts({
+          listing,
+          escrowTokenAccount: escrow,
+          renterTokenAccount,
+          paymentMint,
+          renter: renter.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([renter])
+        .rpc(),
+      "AgentNotAvailable"
+    );
+  });
+
+  it("Releases nothing to the owner before a full day has elapsed!", async () => {
+    const { listing, escrow } = await listAndRent("running-agent");
+
+    await expectFailure(
+      program.methods
+        .withdrawEarnings()
+        .accounts({
+          listing,
+          escrowTokenAccount: escrow,
+          ownerTokenAccount,
+          paymentMint,
+          owner: payer.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([payer])
+        .rpc(),
+      "NothingToWithdraw"
+    );
+
+    const rental = await program.account.listing.fetch(listing);
+    expect(rental.releasedAmount.toNumber()).to.equal(0);
+    expect(await balance(escrow)).to.equal(pricePerDay * rentalDays);
+  });
+
+  it("Refunds unstarted days when the renter releases early!", async () => {
+    const { listing, escrow } = await listAndRent("early-agent");
+    const rental = await program.account.listing.fetch(listing);
+
+    // Only the renter can end a rental before it is over
+    const stranger = Keypair.generate();
+    await expectFailure(release(listing, escrow, stranger), "NotRenter");
+
+    const ownerBefore = await balance(ownerTokenAccount);
+    const renterBefore = await balance(renterTokenAccount);
+    const sig = await release(listing, escrow, renter);
+
+    // Every day started by the release is paid to the owner, the rest is refunded
+    const tx = await provider.connection.getTransaction(sig, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const elapsed = Math.max(0, tx.blockTime - rental.rentalStart.toNumber());
+    const daysStarted = Math.min(rentalDays, Math.ceil(elapsed / SECONDS_PER_DAY));
+    const owed = daysStarted * pricePerDay;
+    const refund = pricePerDay * rentalDays - owed;
+
+    expect((await balance(ownerTokenAccount)) - ownerBefore).to.equal(owed);
+    expect((await balance(renterTokenAccount)) - renterBefore).to.equal(refund);
+    expect(await balance(escrow)).to.equal(0);
+
+    const released = await program.account.listing.fetch(listing);
+    expect(released.isAvailable).to.equal(true);
+    expect(released.renter.toBase58()).to.equal(PublicKey.default.toBase58());
+    expect(released.escrowedAmount.toNumber()).to.equal(0);
+    expect(released.totalEarnings.toNumber()).to.equal(owed);
+  });
+});
@@ -39,6 +39,19 @@ describe('axiom_pohw', () => {
     expect(schemaAccount.authority.toString()).to.equal(authority.publicKey.toString());
   });
 
+  it('Adds an authorized verifier', async () => {
+    await program.methods
+      .addVerifier(authority.publicKey)
+      .accounts({
+        schema: schemaPda,
+        authority: authority.publicKey,
+      })
+      .rpc();
+
+    const schemaAccount = await program.account.schema.fetch(schemaPda);
+    expect(schemaAccount.verifiers.map((v) => v.toString())).to.include(authority.publicKey.toString());
+  });
+
   it('Records human work attestation', async () => {
     // Create a test user
     const user = Keypair.generate();
@@ -74,6 +87,8 @@ describe('axiom_pohw', () => {
         user: user.publicKey,
         schema: schemaPda,
         attestation: attestationPda,
+        verifier: authority.publicKey,
+        instructions: null,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
@@ -90,6 +105,8 @@ describe('axiom_pohw', () => {
     expect(attestationAccount.totalTasks.toNumber()).to.equal(5);
     expect(attestationAccount.qualityScore).to.equal(9500);
     expect(attestationAccount.specializationTier).to.equal(2);
+    expect(attestationAccount.verifier.toString()).to.equal(authority.publicKey.toString());
+    expect(attestationAccount.verifiedAt.toNumber()).to.be.greaterThan(0);
   });
 
   it('Updates human work attestation', async () => {
@@ -127,6 +144,8 @@ describe('axiom_pohw', () => {
         user: user.publicKey,
         schema: schemaPda,
         attestation: attestationPda,
+        verifier: authority.publicKey,
+        instructions: null,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
@@ -148,6 +167,8 @@ describe('axiom_pohw', () => {
         user: user.publicKey,
         schema: schemaPda,
         attestation: attestationPda,
+        verifier: authority.publicKey,
+        instructions: null,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
@@ -165,4 +186,54 @@ describe('axiom_pohw', () => {
     expect(attestationAccount.qualityScore).to.equal(9800);
     expect(attestationAccount.specializationTier).to.equal(2);
   });
-});
\ No newline at end of file
+
+  it('Registers a new schema version', async () => {
+    await program.methods
+      .registerSchemaVersion(2)
+      .accounts({
+        schema: schemaPda,
+        authority: authority.publicKey,
+      })
+      .rpc();
+
+    const schemaAccount = await program.account.schema.fetch(schemaPda);
+    expect(schemaAccount.version).to.equal(2);
+    expect(Array.from(schemaAccount.activeVersions)).to.deep.equal([1, 2]);
+  });
+
+  it('Sets the decay half-life', async () => {
+    const halfLife = new anchor.BN(30 * 24 * 60 * 60); // 30 days
+
+    await program.methods
+      .setDecayHalfLife(halfLife)
+      .accounts({
+        schema: schemaPda,
+        authority: authority.publicKey,
+      })
+      .rpc();
+
+    const schemaAccount = await program.account.schema.fetch(schemaPda);
+    expect(schemaAccount.decayHalfLife.toNumber()).to.equal(halfLife.toNumber());
+  });
+
+  it('Refuses to migrate a schema already in the current layout', async () => {
+    let failed = false;
+    try {
+      await program.methods
+        .migrateSchema()
+        .accounts({
+          payer: authority.publicKey,
+          schema: schemaPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    } catch (err) {
+      failed = true;
+      expect(err.toString()).to.include('AlreadyMigrated');
+    }
+    expect(failed).to.equal(true);
+
+    const schemaAccount = await program.account.schema.fetch(schemaPda);
+    expect(schemaAccount.decayHalfLife.toNumber()).to.equal(30 * 24 * 60 * 60);
+  });
+});
@@ -137,13 +137,16 @@ describe("axiom_staking_comprehensive", () => {
       .signers([payer])
       .rpc();
 
-    // Now unstake tokens
+    // Now unstake tokens into the first unbonding ticket
+    const [unbondingTicketPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("unbonding-ticket"), userStakePda.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
     const tx = await program.methods.unstakeTokens(new anchor.BN(50))
       .accounts({
         pool: poolPda,
         userStake: userStakePda,
-        userTokenAccount: payer.publicKey, // Simplified for test
-        poolTokenAccount: poolPda, // Simplified for test
+        unbondingTicket: unbondingTicketPda,
         stakedTokenMint: stakedTokenMint.publicKey,
         user: payer.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -200,7 +203,7 @@ describe("axiom_staking_comprehensive", () => {
       .rpc();
 
     // Update reputation score
-    const tx = await program.methods.updateReputationScore(new anchor.BN(100))
+    const tx = await program.methods.updateReputationScore()
       .accounts({
         pool: poolPda,
         authority: payer.publicKey,
@@ -243,7 +246,7 @@ describe("axiom_staking_comprehensive", () => {
 
     // Try to update reputation as stranger - this should fail
     try {
-      await program.methods.updateReputationScore(new anchor.BN(100))
+      await program.methods.updateReputationScore()
         .accounts({
           pool: poolPda,
           authority: stranger.publicKey, // Stranger trying to update
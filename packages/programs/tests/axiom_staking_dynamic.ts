@@ -38,6 +38,9 @@ describe('axiom_staking_dynamic', () => {
   // User
   let user: Keypair;
 
+  // Unbonding ticket created by unstaking
+  let unbondingTicketPda: PublicKey;
+
   before(async () => {
     // Create test user
     user = Keypair.generate();
@@ -230,6 +233,12 @@ describe('axiom_staking_dynamic', () => {
   });
 
   it('Unstakes tokens', async () => {
+    // First unbonding ticket of the user
+    [unbondingTicketPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("unbonding_ticket"), user.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
     // Unstake tokens
     const unstakeAmount = new anchor.BN(500000000); // 0.5 token
 
@@ -242,10 +251,12 @@ describe('axiom_staking_dynamic', () => {
           [Buffer.from("user_stake"), user.publicKey.toBuffer()],
           program.programId
         )[0],
-        userTokenAccount: userStakedTokenAccount,
-        poolTokenAccount: poolStakedTokenAccount,
+        unbondingTicket: unbondingTicketPda,
+        rewardTokenAccount: poolRewardTokenAccount,
+        userRewardTokenAccount: userRewardTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([user])
       .rpc();
@@ -263,6 +274,37 @@ describe('axiom_staking_dynamic', () => {
 
     // Verify the stake was reduced correctly
     expect(userStakeAccount.amount.toNumber()).to.equal(1500000000); // 2 - 0.5
+
+    // Pending rewards were paid out, so the debt matches the remaining stake exactly
+    const poolAccount = await program.account.stakingPool.fetch(poolPda);
+    const expectedDebt = userStakeAccount.amount.mul(poolAccount.accRewardPerShare).shrn(40);
+    expect(userStakeAccount.rewardDebt.toString()).to.equal(expectedDebt.toString());
+
+    // The unstaked tokens wait in an unbonding ticket
+    const ticketAccount = await program.account.unbondingTicket.fetch(unbondingTicketPda);
+    expect(ticketAccount.owner.toString()).to.equal(user.publicKey.toString());
+    expect(ticketAccount.amount.toNumber()).to.equal(500000000);
+  });
+
+  it('Rejects withdrawing before the unbonding period ends', async () => {
+    try {
+      await program.methods
+        .withdrawUnbonded()
+        .accounts({
+          user: user.publicKey,
+          pool: poolPda,
+          unbondingTicket: unbondingTicketPda,
+          userTokenAccount: userStakedTokenAccount,
+          poolTokenAccount: poolStakedTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail('Withdrawal should fail during the unbonding period');
+    } catch (error) {
+      expect(error.toString()).to.include('UnbondingPeriodNotElapsed');
+    }
   });
 
   it('Updates reward rate', async () => {
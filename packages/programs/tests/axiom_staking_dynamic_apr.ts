@@ -69,23 +69,27 @@ describe("axiom_staking_dynamic_apr", () => {
       .signers([payer])
       .rpc();
 
-    // Call the calculate_dynamic_apr function with positive attestations
-    const tx = await program.methods.calculateDynamicApr(new anchor.BN(5), new anchor.BN(2))
+    // Call the calculate_dynamic_apr function; attestations are read from
+    // remaining_accounts, so with none supplied only the base APR applies
+    const tx = await program.methods.calculateDynamicApr()
       .accounts({
         pool: poolPda,
         stakedTokenMint: stakedTokenMint.publicKey,
-        attestationAuthority: payer.publicKey, // Simplified for test
         authority: payer.publicKey,
+        systemProgram: SystemProgram.programId,
       })
       .signers([payer])
       .rpc();
       
     console.log("Calculate dynamic APR transaction signature", tx);
     
-    // Verify the reward rate was updated based on attestations
+    // Verify the APR was derived from verified attestations only
     const poolAccount = await program.account.stakingPool.fetch(poolPda);
-    // With 5 positive (+2.5%) and 2 negative (-2%) attestations, APR should be 10.5% (1050 basis points)
-    // Reward rate should be 1050 * 100 = 105000
-    expect(poolAccount.rewardRate.toString()).toBe("105000");
+    // With no verified attestations, APR should be the 10% base (1000 basis points)
+    expect(poolAccount.dynamicApr.toString()).toBe("1000");
+    // The emission rate is left to the authority's schedule
+    expect(poolAccount.rewardRate.toString()).toBe("1000");
+    expect(poolAccount.positiveAttestations.toString()).toBe("0");
+    expect(poolAccount.negativeAttestations.toString()).toBe("0");
   });
 });
\ No newline at end of file
@@ -2,6 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { AxiomTrainEarn } from "../target/types/axiom_train_earn";
 import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
 
 describe("axiom_train_earn", () => {
   // Configure the client to use the local cluster.
@@ -120,69 +121,12 @@ describe("axiom_train_earn", () => {
     console.log("Task completion user:", taskCompletion.user.toBase58());
     console.log("Task completion data:", taskCompletion.taskData);
     console.log("Task completion timestamp:", taskCompletion.timestamp.toString());
+    console.log("Status:", Object.keys(taskCompletion.status)[0]);
     console.log("Reward claimed:", taskCompletion.rewardClaimed);
   });
 
-  it("Claims a reward!", async () => {
-    // This would require setting up token accounts and mint
-    // For now, we'll just test that the instruction is properly structured
-    console.log("Claim reward test placeholder");
-  });
-
-  it("Issues a task attestation!", async () => {
-    // This would require setting up the attestations program
-    // For now, we'll just test that the instruction is properly structured
-    console.log("Issue task attestation test placeholder");
-  });
-});import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
-import { AxiomTrainEarn } from "../target/types/axiom_train_earn";
-import { PublicKey, SystemProgram } from "@solana/web3.js";
-
-describe("axiom_train_earn", () => {
-  // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
-
-  const program = anchor.workspace.AxiomTrainEarn as Program<AxiomTrainEarn>;
-  const provider = anchor.getProvider();
-  const payer = (provider as any).wallet.payer;
-
-  it("Initializes the train-to-earn configuration!", async () => {
-    // Derive the train earn config PDA
-    const [trainEarnConfigPda, bump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("train-earn-config")],
-      program.programId
-    );
-
-    // Call the initialize function
-    const tx = await program.methods.initialize(payer.publicKey)
-      .accounts({
-        trainEarnConfig: trainEarnConfigPda,
-        payer: payer.publicKey,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([payer])
-      .rpc();
-
-    console.log("Transaction signature", tx);
-
-    // Fetch the created account
-    const trainEarnConfig = await program.account.trainEarnConfig.fetch(trainEarnConfigPda);
-    console.log("Train earn config authority:", trainEarnConfig.authority.toBase58());
-    console.log("Total tasks:", trainEarnConfig.totalTasks.toString());
-    console.log("Total rewards distributed:", trainEarnConfig.totalRewardsDistributed.toString());
-  });
-
-  it("Creates a task pool!", async () => {
+  it("Configures task reviewers!", async () => {
     const taskPoolName = "Image Classification Task";
-    const rewardAmount = new anchor.BN(1000000); // 1 token (assuming 6 decimals)
-    const maxCompletions = new anchor.BN(100);
-
-    // Derive the train earn config PDA
-    const [trainEarnConfigPda, configBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("train-earn-config")],
-      program.programId
-    );
 
     // Derive the task pool PDA
     const [taskPoolPda, poolBump] = PublicKey.findProgramAddressSync(
@@ -190,73 +134,45 @@ describe("axiom_train_earn", () => {
       program.programId
     );
 
-    // Call the create_task_pool function
-    const tx = await program.methods.createTaskPool(taskPoolName, rewardAmount, maxCompletions)
+    // Allow-list the pool authority, require 1 approval and pay reviewers 10%
+    const tx = await program.methods.setReviewConfig([payer.publicKey], 0, 1, 1000)
       .accounts({
         taskPool: taskPoolPda,
         authority: payer.publicKey,
-        systemProgram: SystemProgram.programId,
       })
       .signers([payer])
       .rpc();
 
-    console.log("Create task pool transaction signature", tx);
+    console.log("Set review config transaction signature", tx);
 
-    // Fetch the created task pool
+    // Fetch the updated task pool
     const taskPool = await program.account.taskPool.fetch(taskPoolPda);
-    console.log("Task pool name:", taskPool.name);
-    console.log("Reward amount:", taskPool.rewardAmount.toString());
-    console.log("Max completions:", taskPool.maxCompletions.toString());
-    console.log("Current completions:", taskPool.currentCompletions.toString());
-    console.log("Is active:", taskPool.isActive);
+    console.log("Reviewers:", taskPool.reviewers.map((r) => r.toBase58()));
+    console.log("Approval quorum:", taskPool.approvalQuorum);
+    console.log("Reviewer reward bps:", taskPool.reviewerRewardBps);
   });
 
-  it("Completes a task!", async () => {
+  it("Rejects a quorum the allow-list cannot reach!", async () => {
     const taskPoolName = "Image Classification Task";
-    const taskData = "Classified image as cat";
 
-    // Derive the train earn config PDA
-    const [trainEarnConfigPda, configBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("train-earn-config")],
-      program.programId
-    );
-
-    // Derive the task pool PDA
-    const [taskPoolPda, poolBump] = PublicKey.findProgramAddressSync(
+    const [taskPoolPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("task-pool"), payer.publicKey.toBuffer(), Buffer.from(taskPoolName)],
       program.programId
     );
 
-    // Derive the task completion PDA (first completion)
-    const [taskCompletionPda, completionBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("task-completion"), taskPoolPda.toBuffer(), payer.publicKey.toBuffer(), new Uint8Array([0, 0, 0, 0, 0, 0, 0, 0])],
-      program.programId
-    );
-
-    // Call the complete_task function
-    const tx = await program.methods.completeTask(taskData)
-      .accounts({
-        taskPool: taskPoolPda,
-        taskCompletion: taskCompletionPda,
-        trainEarnConfig: trainEarnConfigPda,
-        user: payer.publicKey,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([payer])
-      .rpc();
-
-    console.log("Complete task transaction signature", tx);
-
-    // Fetch the updated task pool
-    const taskPool = await program.account.taskPool.fetch(taskPoolPda);
-    console.log("Current completions after task completion:", taskPool.currentCompletions.toString());
-
-    // Fetch the task completion
-    const taskCompletion = await program.account.taskCompletion.fetch(taskCompletionPda);
-    console.log("Task completion user:", taskCompletion.user.toBase58());
-    console.log("Task completion data:", taskCompletion.taskData);
-    console.log("Task completion timestamp:", taskCompletion.timestamp.toString());
-    console.log("Reward claimed:", taskCompletion.rewardClaimed);
+    // Two approvals from a single allow-listed reviewer can never happen
+    try {
+      await program.methods.setReviewConfig([payer.publicKey], 0, 2, 1000)
+        .accounts({
+          taskPool: taskPoolPda,
+          authority: payer.publicKey,
+        })
+        .signers([payer])
+        .rpc();
+      expect.fail("Expected the review config to be rejected");
+    } catch (err) {
+      expect(err.toString()).to.include("InvalidQuorum");
+    }
   });
 
   it("Claims a reward!", async () => {